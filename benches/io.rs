@@ -0,0 +1,171 @@
+//! Benchmarks of typed reads and writes,
+//! in both byte orders, known at compile time or at run time,
+//! against direct use of `byteorder`.
+#[macro_use]
+extern crate criterion;
+extern crate byteordered;
+
+use byteordered::byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteordered::{ByteOrdered, Endianness};
+use criterion::{black_box, Criterion, Throughput};
+
+/// The number of values processed in each iteration.
+const LEN: usize = 4096;
+
+fn data() -> Vec<u8> {
+    (0..LEN * 4).map(|i| i as u8).collect()
+}
+
+fn bench_read_u32(c: &mut Criterion) {
+    let data = data();
+    let mut group = c.benchmark_group("read_u32");
+    group.throughput(Throughput::Bytes(data.len() as u64));
+
+    macro_rules! bench {
+        ($name:expr, $make:expr) => {
+            group.bench_function($name, |b| {
+                b.iter(|| {
+                    let mut rdr = $make(&data[..]);
+                    let mut acc = 0u32;
+                    for _ in 0..LEN {
+                        acc = acc.wrapping_add(rdr.read_u32().unwrap());
+                    }
+                    black_box(acc)
+                })
+            });
+        };
+    }
+    bench!("static/le", ByteOrdered::le);
+    bench!("static/be", ByteOrdered::be);
+    bench!("runtime/le", |d| ByteOrdered::runtime(
+        d,
+        black_box(Endianness::Little)
+    ));
+    bench!("runtime/be", |d| ByteOrdered::runtime(
+        d,
+        black_box(Endianness::Big)
+    ));
+
+    group.bench_function("byteorder/le", |b| {
+        b.iter(|| {
+            let mut rdr = &data[..];
+            let mut acc = 0u32;
+            for _ in 0..LEN {
+                acc = acc.wrapping_add(rdr.read_u32::<LittleEndian>().unwrap());
+            }
+            black_box(acc)
+        })
+    });
+    group.bench_function("byteorder/be", |b| {
+        b.iter(|| {
+            let mut rdr = &data[..];
+            let mut acc = 0u32;
+            for _ in 0..LEN {
+                acc = acc.wrapping_add(rdr.read_u32::<BigEndian>().unwrap());
+            }
+            black_box(acc)
+        })
+    });
+    group.finish();
+}
+
+fn bench_read_u32_into(c: &mut Criterion) {
+    let data = data();
+    let mut group = c.benchmark_group("read_u32_into");
+    group.throughput(Throughput::Bytes(data.len() as u64));
+    let mut dst = vec![0u32; LEN];
+
+    macro_rules! bench {
+        ($name:expr, $make:expr) => {
+            group.bench_function($name, |b| {
+                b.iter(|| {
+                    let mut rdr = $make(&data[..]);
+                    rdr.read_u32_into(&mut dst).unwrap();
+                    black_box(&dst);
+                })
+            });
+        };
+    }
+    bench!("static/le", ByteOrdered::le);
+    bench!("static/be", ByteOrdered::be);
+    bench!("runtime/le", |d| ByteOrdered::runtime(
+        d,
+        black_box(Endianness::Little)
+    ));
+    bench!("runtime/be", |d| ByteOrdered::runtime(
+        d,
+        black_box(Endianness::Big)
+    ));
+
+    group.bench_function("byteorder/le", |b| {
+        b.iter(|| {
+            let mut rdr = &data[..];
+            rdr.read_u32_into::<LittleEndian>(&mut dst).unwrap();
+            black_box(&dst);
+        })
+    });
+    group.bench_function("byteorder/be", |b| {
+        b.iter(|| {
+            let mut rdr = &data[..];
+            rdr.read_u32_into::<BigEndian>(&mut dst).unwrap();
+            black_box(&dst);
+        })
+    });
+    group.finish();
+}
+
+fn bench_write_u32(c: &mut Criterion) {
+    let mut group = c.benchmark_group("write_u32");
+    group.throughput(Throughput::Bytes(LEN as u64 * 4));
+    let mut sink = Vec::with_capacity(LEN * 4);
+
+    macro_rules! bench {
+        ($name:expr, $make:expr) => {
+            group.bench_function($name, |b| {
+                b.iter(|| {
+                    sink.clear();
+                    let mut wtr = $make(&mut sink);
+                    for i in 0..LEN as u32 {
+                        wtr.write_u32(black_box(i)).unwrap();
+                    }
+                })
+            });
+        };
+    }
+    bench!("static/le", ByteOrdered::le);
+    bench!("static/be", ByteOrdered::be);
+    bench!("runtime/le", |s| ByteOrdered::runtime(
+        s,
+        black_box(Endianness::Little)
+    ));
+    bench!("runtime/be", |s| ByteOrdered::runtime(
+        s,
+        black_box(Endianness::Big)
+    ));
+
+    group.bench_function("byteorder/le", |b| {
+        b.iter(|| {
+            sink.clear();
+            for i in 0..LEN as u32 {
+                sink.write_u32::<LittleEndian>(black_box(i)).unwrap();
+            }
+        })
+    });
+    group.bench_function("byteorder/be", |b| {
+        b.iter(|| {
+            sink.clear();
+            for i in 0..LEN as u32 {
+                sink.write_u32::<BigEndian>(black_box(i)).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_read_u32,
+    bench_read_u32_into,
+    bench_write_u32
+);
+criterion_main!(benches);
@@ -1,9 +1,17 @@
 //! Base Endianness type module.
 
 use byteorder::{BigEndian, ByteOrder, LittleEndian, NativeEndian, ReadBytesExt, WriteBytesExt};
+#[cfg(feature = "half")]
+use half::{f16, slice::HalfFloatSliceExt};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::default::Default;
-use std::io::{Read, Result as IoResult, Write};
+use std::error::Error;
+use std::fmt;
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write};
 use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ops::RangeInclusive;
 
 /// Trait for any type which has an opposite type. This is used to convert
 /// immaterial types representing "little endian" into "big endian" and vice
@@ -47,6 +55,28 @@ impl StaticNative for LittleEndian {
     }
 }
 
+/// Trait for the byte order marker types of the [`byteorder`] crate
+/// which can be used as the parameter of a [`StaticEndianness`].
+///
+/// This trait is implemented for [`byteorder::LittleEndian`][le] and
+/// [`byteorder::BigEndian`][be],
+/// and therefore also for the aliases
+/// `NativeEndian` and `NetworkEndian`.
+/// It enables code which is generic over a `byteorder` marker type
+/// to obtain a byte order aware reader or writer
+/// (see [`ByteOrdered::from_byteorder_marker`]).
+///
+/// [`byteorder`]: https://docs.rs/byteorder
+/// [`StaticEndianness`]: struct.StaticEndianness.html
+/// [be]: https://docs.rs/byteorder/*/byteorder/enum.BigEndian.html
+/// [le]: https://docs.rs/byteorder/*/byteorder/enum.LittleEndian.html
+/// [`ByteOrdered::from_byteorder_marker`]: struct.ByteOrdered.html#method.from_byteorder_marker
+pub trait FromByteOrder: ByteOrder + HasOpposite + StaticNative {}
+
+impl FromByteOrder for LittleEndian {}
+
+impl FromByteOrder for BigEndian {}
+
 /// General trait for types that can
 /// serialize and deserialize bytes in some byte order.
 ///
@@ -64,12 +94,80 @@ pub trait Endian: Copy + private::Sealed {
     /// A type which can represent a byte order that is opposite to this one.
     type Opposite;
 
+    /// A value of this type which can be obtained in generic code
+    /// without further bounds, such as `Default`.
+    ///
+    /// - For [`StaticEndianness`], this is the only possible value,
+    ///   so it always represents the byte order of the type.
+    /// - For [`Endianness`], this is the system's native byte order,
+    ///   as in [`Endianness::native_const`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byteordered::{Endian, Endianness, StaticEndianness};
+    /// use byteordered::byteorder::BigEndian;
+    ///
+    /// fn read_magic<E: Endian>(data: &[u8]) -> u16 {
+    ///     E::DEFAULT.read_u16(data).unwrap()
+    /// }
+    ///
+    /// assert_eq!(read_magic::<StaticEndianness<BigEndian>>(&[0x4D, 0x4D]), 0x4D4D);
+    /// ```
+    ///
+    /// [`StaticEndianness`]: struct.StaticEndianness.html
+    /// [`Endianness`]: enum.Endianness.html
+    /// [`Endianness::native_const`]: enum.Endianness.html#method.native_const
+    const DEFAULT: Self;
+
     /// Checks whether this value represents the system's native endianness.
     fn is_native(self) -> bool;
 
     /// Converts the receiver into its opposite.
     fn into_opposite(self) -> Self::Opposite;
 
+    /// Reads a signed 8 bit integer from the given reader.
+    ///
+    /// Since this reads a single byte,
+    /// the byte order is irrelevant.
+    /// It is included so that generic code
+    /// can read every primitive integer through this trait.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    fn read_i8<R>(self, reader: R) -> IoResult<i8>
+    where
+        R: Read,
+    {
+        self.read_u8(reader).map(|x| x as i8)
+    }
+
+    /// Reads an unsigned 8 bit integer from the given reader.
+    ///
+    /// Since this reads a single byte,
+    /// the byte order is irrelevant.
+    /// It is included so that generic code
+    /// can read every primitive integer through this trait.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    fn read_u8<R>(self, mut reader: R) -> IoResult<u8>
+    where
+        R: Read,
+    {
+        let mut buf = [0; 1];
+        reader.read_exact(&mut buf)?;
+        Ok(buf[0])
+    }
+
     /// Reads a signed 16 bit integer from the given reader.
     ///
     /// # Errors
@@ -134,6 +232,29 @@ pub trait Endian: Copy + private::Sealed {
         Ok(())
     }
 
+    /// Reads a signed 24 bit integer from the given reader,
+    /// sign-extended to 32 bits.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    fn read_i24<R>(self, reader: R) -> IoResult<i32>
+    where
+        R: Read;
+
+    /// Reads an unsigned 24 bit integer from the given reader.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    fn read_u24<R>(self, reader: R) -> IoResult<u32>
+    where
+        R: Read;
+
     /// Reads a signed 32 bit integer from the given reader.
     ///
     /// # Errors
@@ -326,6 +447,110 @@ pub trait Endian: Copy + private::Sealed {
         Ok(())
     }
 
+    /// Reads a signed integer of `nbytes` bytes from the given reader,
+    /// sign-extended to 64 bits.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 8,
+    /// as in `byteorder`.
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    fn read_int<R>(self, reader: R, nbytes: usize) -> IoResult<i64>
+    where
+        R: Read;
+
+    /// Reads an unsigned integer of `nbytes` bytes from the given reader.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 8,
+    /// as in `byteorder`.
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    fn read_uint<R>(self, reader: R, nbytes: usize) -> IoResult<u64>
+    where
+        R: Read;
+
+    /// Reads a signed integer of `nbytes` bytes from the given reader,
+    /// sign-extended to 128 bits.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 16,
+    /// as in `byteorder`.
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    fn read_int128<R>(self, reader: R, nbytes: usize) -> IoResult<i128>
+    where
+        R: Read;
+
+    /// Reads an unsigned integer of `nbytes` bytes from the given reader.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 16,
+    /// as in `byteorder`.
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    fn read_uint128<R>(self, reader: R, nbytes: usize) -> IoResult<u128>
+    where
+        R: Read;
+
+    /// Reads a IEEE754 half-precision (2 bytes) floating point number from
+    /// the given reader.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[cfg(feature = "half")]
+    #[inline]
+    fn read_f16<R>(self, reader: R) -> IoResult<f16>
+    where
+        R: Read,
+    {
+        self.read_u16(reader).map(f16::from_bits)
+    }
+
+    /// Reads a sequence of IEEE754 half-precision (2 bytes) floating point numbers
+    /// from the given reader.
+    ///
+    /// The given buffer is either filled completely or an error is returned.
+    /// If an error is returned,
+    /// the contents of `dst` are unspecified.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[cfg(feature = "half")]
+    #[inline]
+    fn read_f16_into<R>(self, reader: R, dst: &mut [f16]) -> IoResult<()>
+    where
+        R: Read,
+    {
+        self.read_u16_into(reader, dst.reinterpret_cast_mut())
+    }
+
     /// Reads a IEEE754 single-precision (4 bytes) floating point number from
     /// the given reader.
     ///
@@ -394,6 +619,46 @@ pub trait Endian: Copy + private::Sealed {
         Ok(())
     }
 
+    /// Writes a signed 8 bit integer to the given writer.
+    ///
+    /// Since this writes a single byte,
+    /// the byte order is irrelevant.
+    /// It is included so that generic code
+    /// can write every primitive integer through this trait.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    fn write_i8<W>(self, writer: W, v: i8) -> IoResult<()>
+    where
+        W: Write,
+    {
+        self.write_u8(writer, v as u8)
+    }
+
+    /// Writes an unsigned 8 bit integer to the given writer.
+    ///
+    /// Since this writes a single byte,
+    /// the byte order is irrelevant.
+    /// It is included so that generic code
+    /// can write every primitive integer through this trait.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    fn write_u8<W>(self, mut writer: W, v: u8) -> IoResult<()>
+    where
+        W: Write,
+    {
+        writer.write_all(&[v])
+    }
+
     /// Writes a signed 16 bit integer to the given writer.
     ///
     /// # Errors
@@ -416,6 +681,36 @@ pub trait Endian: Copy + private::Sealed {
     where
         W: Write;
 
+    /// Writes a signed 24 bit integer to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the value does not fit in 24 bits.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    fn write_i24<W>(self, writer: W, v: i32) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes an unsigned 24 bit integer to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the value does not fit in 24 bits.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    fn write_u24<W>(self, writer: W, v: u32) -> IoResult<()>
+    where
+        W: Write;
+
     /// Writes a signed 32 bit integer to the given writer.
     ///
     /// # Errors
@@ -482,6 +777,99 @@ pub trait Endian: Copy + private::Sealed {
     where
         W: Write;
 
+    /// Writes the `nbytes` least significant bytes
+    /// of a signed integer to the given writer.
+    ///
+    /// A value which does not fit in `nbytes` bytes is truncated
+    /// to its `nbytes` least significant bytes.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 8,
+    /// as in `byteorder`.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_int<W>(self, writer: W, v: i64, nbytes: usize) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes the `nbytes` least significant bytes
+    /// of an unsigned integer to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 8,
+    /// or if the value does not fit in `nbytes` bytes,
+    /// as in `byteorder`.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_uint<W>(self, writer: W, v: u64, nbytes: usize) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes the `nbytes` least significant bytes
+    /// of a signed 128 bit integer to the given writer.
+    ///
+    /// A value which does not fit in `nbytes` bytes is truncated
+    /// to its `nbytes` least significant bytes.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 16,
+    /// as in `byteorder`.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_int128<W>(self, writer: W, v: i128, nbytes: usize) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes the `nbytes` least significant bytes
+    /// of an unsigned 128 bit integer to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 16,
+    /// or if the value does not fit in `nbytes` bytes,
+    /// as in `byteorder`.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_uint128<W>(self, writer: W, v: u128, nbytes: usize) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a IEEE754 half-precision (2 bytes) floating point number to
+    /// the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[cfg(feature = "half")]
+    #[inline]
+    fn write_f16<W>(self, writer: W, v: f16) -> IoResult<()>
+    where
+        W: Write,
+    {
+        self.write_u16(writer, v.to_bits())
+    }
+
     /// Writes a IEEE754 single-precision (4 bytes) floating point number to
     /// the given writer.
     ///
@@ -505,20 +893,160 @@ pub trait Endian: Copy + private::Sealed {
     fn write_f64<W>(self, writer: W, v: f64) -> IoResult<()>
     where
         W: Write;
-}
 
-/// A data type representing a byte order known in compile time.
-/// Unlike the types provided in `byteorder`, this type can be constructed.
-///
-/// The parameter type `E` can be one of either [`byteorder::BigEndian`][be]
-/// or [`byteorder::LittleEndian`][le].
-///
-/// [be]: https://docs.rs/byteorder/*/byteorder/enum.BigEndian.html
-/// [le]: https://docs.rs/byteorder/*/byteorder/enum.LittleEndian.html
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct StaticEndianness<E>(PhantomData<E>);
+    /// Writes a sequence of signed 16 bit integers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_i16_into<W>(self, writer: W, src: &[i16]) -> IoResult<()>
+    where
+        W: Write;
 
-impl<E> Default for StaticEndianness<E> {
+    /// Writes a sequence of unsigned 16 bit integers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_u16_into<W>(self, writer: W, src: &[u16]) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a sequence of signed 32 bit integers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_i32_into<W>(self, writer: W, src: &[i32]) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a sequence of unsigned 32 bit integers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_u32_into<W>(self, writer: W, src: &[u32]) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a sequence of signed 64 bit integers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_i64_into<W>(self, writer: W, src: &[i64]) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a sequence of unsigned 64 bit integers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_u64_into<W>(self, writer: W, src: &[u64]) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a sequence of signed 128 bit integers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_i128_into<W>(self, writer: W, src: &[i128]) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a sequence of unsigned 128 bit integers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_u128_into<W>(self, writer: W, src: &[u128]) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a sequence of IEEE754 single-precision (4 bytes) floating point numbers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_f32_into<W>(self, writer: W, src: &[f32]) -> IoResult<()>
+    where
+        W: Write;
+
+    /// Writes a sequence of IEEE754 double-precision (8 bytes) floating point numbers to the given writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written at once.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write_f64_into<W>(self, writer: W, src: &[f64]) -> IoResult<()>
+    where
+        W: Write;
+}
+
+/// A data type representing a byte order known in compile time.
+/// Unlike the types provided in `byteorder`, this type can be constructed.
+///
+/// The parameter type `E` can be one of either [`byteorder::BigEndian`][be]
+/// or [`byteorder::LittleEndian`][le].
+///
+/// [be]: https://docs.rs/byteorder/*/byteorder/enum.BigEndian.html
+/// [le]: https://docs.rs/byteorder/*/byteorder/enum.LittleEndian.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct StaticEndianness<E>(PhantomData<E>);
+
+impl<E> Default for StaticEndianness<E> {
     #[inline]
     fn default() -> Self {
         StaticEndianness::new()
@@ -555,6 +1083,9 @@ impl PartialEq<StaticEndianness<BigEndian>> for StaticEndianness<LittleEndian> {
     }
 }
 
+// `NativeEndian` is an alias of either `LittleEndian` or `BigEndian`,
+// so these also resolve comparisons involving `StaticEndianness<NativeEndian>`
+// to the target's byte order.
 impl PartialEq<Endianness> for StaticEndianness<BigEndian> {
     #[inline]
     fn eq(&self, e: &Endianness) -> bool {
@@ -576,6 +1107,20 @@ where
     type Opposite = StaticEndianness<E::Opposite>;
 }
 
+/// The range of values of a signed 24 bit integer.
+pub(crate) const I24_RANGE: RangeInclusive<i32> = -0x80_0000..=0x7F_FFFF;
+
+/// The range of values of an unsigned 24 bit integer.
+pub(crate) const U24_RANGE: RangeInclusive<u32> = 0..=0xFF_FFFF;
+
+/// Creates an error for a value which does not fit in 24 bits.
+pub(crate) fn not_24_bit<T: ::std::fmt::Display>(x: T) -> IoError {
+    IoError::new(
+        ErrorKind::InvalidInput,
+        format!("{} does not fit in 24 bits", x),
+    )
+}
+
 /// Private macro for endiannesses known at compile time,
 /// which implements a `read_*` method
 /// by delegating a call to the same method on `ReadBytesExt`.
@@ -591,6 +1136,21 @@ macro_rules! fn_static_endianness_read {
     };
 }
 
+/// Private macro for endiannesses known at compile time,
+/// which implements a `read_*` method of a variable number of bytes
+/// by delegating a call to the same method on `ReadBytesExt`.
+macro_rules! fn_static_endianness_read_n {
+    ($method:ident, $e:ty, $out:ty) => {
+        #[inline]
+        fn $method<S>(self, mut src: S, nbytes: usize) -> IoResult<$out>
+        where
+            S: Read,
+        {
+            src.$method::<$e>(nbytes)
+        }
+    };
+}
+
 /// Private macro for endiannesses known at compile time,
 /// which implements a `read_*_into` method
 /// by delegating a call to the same method on `ReadBytesExt`.
@@ -621,6 +1181,58 @@ macro_rules! fn_static_endianness_write {
     };
 }
 
+/// Private macro for endiannesses known at compile time,
+/// which implements a `write_*` method of a variable number of bytes
+/// by delegating a call to the same method on `WriteBytesExt`.
+macro_rules! fn_static_endianness_write_n {
+    ($method:ident, $e:ty, $out:ty) => {
+        #[inline]
+        fn $method<W>(self, mut src: W, x: $out, nbytes: usize) -> IoResult<()>
+        where
+            W: Write,
+        {
+            src.$method::<$e>(x, nbytes)
+        }
+    };
+}
+
+/// Private macro for endiannesses known at compile time,
+/// which implements a `write_*_into` method
+/// by encoding all values with the same method on `ByteOrder`
+/// and writing the resulting bytes at once.
+macro_rules! fn_static_endianness_write_into {
+    ($method:ident, $e:ty, $i:ty) => {
+        #[inline]
+        fn $method<W>(self, mut dst: W, src: &[$i]) -> IoResult<()>
+        where
+            W: Write,
+        {
+            let mut buf = vec![0; src.len() * size_of::<$i>()];
+            <$e>::$method(src, &mut buf);
+            dst.write_all(&buf)
+        }
+    };
+}
+
+/// Private macro for endiannesses known at compile time,
+/// which implements a `write_*24` method
+/// by checking that the value fits in 24 bits
+/// and delegating a call to the same method on `WriteBytesExt`.
+macro_rules! fn_static_endianness_write_24 {
+    ($method:ident, $e:ty, $out:ty, $range:expr) => {
+        #[inline]
+        fn $method<W>(self, mut src: W, x: $out) -> IoResult<()>
+        where
+            W: Write,
+        {
+            if !$range.contains(&x) {
+                return Err(not_24_bit(x));
+            }
+            src.$method::<$e>(x)
+        }
+    };
+}
+
 impl<E> Endian for StaticEndianness<E>
 where
     E: HasOpposite,
@@ -629,6 +1241,8 @@ where
 {
     type Opposite = StaticEndianness<E::Opposite>;
 
+    const DEFAULT: Self = StaticEndianness(PhantomData);
+
     #[inline]
     fn into_opposite(self) -> Self::Opposite {
         StaticEndianness(PhantomData)
@@ -641,6 +1255,8 @@ where
 
     fn_static_endianness_read!(read_i16, E, i16);
     fn_static_endianness_read!(read_u16, E, u16);
+    fn_static_endianness_read!(read_i24, E, i32);
+    fn_static_endianness_read!(read_u24, E, u32);
     fn_static_endianness_read!(read_i32, E, i32);
     fn_static_endianness_read!(read_u32, E, u32);
     fn_static_endianness_read!(read_i64, E, i64);
@@ -650,6 +1266,11 @@ where
     fn_static_endianness_read!(read_f32, E, f32);
     fn_static_endianness_read!(read_f64, E, f64);
 
+    fn_static_endianness_read_n!(read_int, E, i64);
+    fn_static_endianness_read_n!(read_uint, E, u64);
+    fn_static_endianness_read_n!(read_int128, E, i128);
+    fn_static_endianness_read_n!(read_uint128, E, u128);
+
     fn_static_endianness_read_into!(read_i16_into, E, i16);
     fn_static_endianness_read_into!(read_u16_into, E, u16);
     fn_static_endianness_read_into!(read_i32_into, E, i32);
@@ -663,6 +1284,8 @@ where
 
     fn_static_endianness_write!(write_i16, E, i16);
     fn_static_endianness_write!(write_u16, E, u16);
+    fn_static_endianness_write_24!(write_i24, E, i32, I24_RANGE);
+    fn_static_endianness_write_24!(write_u24, E, u32, U24_RANGE);
     fn_static_endianness_write!(write_i32, E, i32);
     fn_static_endianness_write!(write_u32, E, u32);
     fn_static_endianness_write!(write_i64, E, i64);
@@ -671,6 +1294,22 @@ where
     fn_static_endianness_write!(write_u128, E, u128);
     fn_static_endianness_write!(write_f32, E, f32);
     fn_static_endianness_write!(write_f64, E, f64);
+
+    fn_static_endianness_write_n!(write_int, E, i64);
+    fn_static_endianness_write_n!(write_uint, E, u64);
+    fn_static_endianness_write_n!(write_int128, E, i128);
+    fn_static_endianness_write_n!(write_uint128, E, u128);
+
+    fn_static_endianness_write_into!(write_i16_into, E, i16);
+    fn_static_endianness_write_into!(write_u16_into, E, u16);
+    fn_static_endianness_write_into!(write_i32_into, E, i32);
+    fn_static_endianness_write_into!(write_u32_into, E, u32);
+    fn_static_endianness_write_into!(write_i64_into, E, i64);
+    fn_static_endianness_write_into!(write_u64_into, E, u64);
+    fn_static_endianness_write_into!(write_i128_into, E, i128);
+    fn_static_endianness_write_into!(write_u128_into, E, u128);
+    fn_static_endianness_write_into!(write_f32_into, E, f32);
+    fn_static_endianness_write_into!(write_f64_into, E, f64);
 }
 
 /// Enumerate for materializing
@@ -684,12 +1323,38 @@ where
 /// Using this type as the generic endianness type `E` in a `ByteOrdered`
 /// is useful when this information can only be retrieved
 /// from a source that is unknown to the compiler.
+///
+/// This enum is non-exhaustive,
+/// so that other byte orders (such as middle endian)
+/// can be supported in the future without breaking dependent code.
+/// Rather than matching on it,
+/// prefer [`is_little`], [`is_big`], [`select`] or [`fold`].
+///
+/// The enum has the layout of a `u8`,
+/// which makes it safe to embed in C-compatible structures.
+/// The discriminants are part of the stable API:
+/// `Little` is `0` and `Big` is `1`,
+/// as can be observed with `as u8`.
+/// Any byte order added in the future will take a new value.
+///
+/// ```
+/// # use byteordered::Endianness;
+/// assert_eq!(Endianness::Little as u8, 0);
+/// assert_eq!(Endianness::Big as u8, 1);
+/// ```
+///
+/// [`is_little`]: #method.is_little
+/// [`is_big`]: #method.is_big
+/// [`select`]: #method.select
+/// [`fold`]: #method.fold
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
 pub enum Endianness {
     /// Little Endian
-    Little,
+    Little = 0,
     /// Big Endian
-    Big,
+    Big = 1,
 }
 
 impl From<StaticEndianness<LittleEndian>> for Endianness {
@@ -720,6 +1385,58 @@ impl PartialEq<StaticEndianness<LittleEndian>> for Endianness {
     }
 }
 
+/// Obtains the byte order from its stable `u8` representation,
+/// `0` for little endian and `1` for big endian.
+///
+/// # Examples
+///
+/// ```
+/// # use byteordered::Endianness;
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(Endianness::try_from(0), Ok(Endianness::Little));
+/// assert_eq!(Endianness::try_from(1), Ok(Endianness::Big));
+/// assert!(Endianness::try_from(2).is_err());
+/// ```
+impl TryFrom<u8> for Endianness {
+    type Error = InvalidEndiannessTag;
+
+    #[inline]
+    fn try_from(tag: u8) -> Result<Self, Self::Error> {
+        match tag {
+            0 => Ok(Endianness::Little),
+            1 => Ok(Endianness::Big),
+            _ => Err(InvalidEndiannessTag(tag)),
+        }
+    }
+}
+
+/// The error type returned when a byte
+/// does not represent any byte order.
+///
+/// See the [`TryFrom<u8>`] implementation of [`Endianness`].
+///
+/// [`TryFrom<u8>`]: enum.Endianness.html#impl-TryFrom%3Cu8%3E
+/// [`Endianness`]: enum.Endianness.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidEndiannessTag(u8);
+
+impl InvalidEndiannessTag {
+    /// Retrieves the offending byte.
+    #[inline]
+    pub fn tag(self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for InvalidEndiannessTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid byte order tag {}", self.0)
+    }
+}
+
+impl Error for InvalidEndiannessTag {}
+
 /// Private macro for endiannesses known at run time,
 /// which implements a `read_*` method
 /// by delegating a call to the same method on `ReadBytesExt`.
@@ -738,9 +1455,30 @@ macro_rules! fn_runtime_endianness_read {
     };
 }
 
+/// Private macro for endiannesses known at run time,
+/// which implements a `read_*` method of a variable number of bytes
+/// by delegating a call to the same method on `ReadBytesExt`.
+macro_rules! fn_runtime_endianness_read_n {
+    ($method:ident, $out:ty) => {
+        #[inline]
+        fn $method<S>(self, mut src: S, nbytes: usize) -> IoResult<$out>
+        where
+            S: Read,
+        {
+            match self {
+                Endianness::Little => src.$method::<LittleEndian>(nbytes),
+                Endianness::Big => src.$method::<BigEndian>(nbytes),
+            }
+        }
+    };
+}
+
 /// Private macro for endiannesses known at run time,
 /// which implements a `read_*_into` method
 /// by delegating a call to the same method on `ReadBytesExt`.
+/// This fills the whole destination with a single `read_exact`
+/// and swaps the bytes of each element in place where needed,
+/// rather than reading one element at a time.
 macro_rules! fn_runtime_endianness_read_into {
     ($method:ident, $out:ty) => {
         #[inline]
@@ -774,6 +1512,67 @@ macro_rules! fn_runtime_endianness_write {
     };
 }
 
+/// Private macro for endiannesses known at run time,
+/// which implements a `write_*` method of a variable number of bytes
+/// by delegating a call to the same method on `WriteBytesExt`.
+macro_rules! fn_runtime_endianness_write_n {
+    ($method:ident, $i:ty) => {
+        #[inline]
+        fn $method<S>(self, mut src: S, v: $i, nbytes: usize) -> IoResult<()>
+        where
+            S: Write,
+        {
+            match self {
+                Endianness::Little => src.$method::<LittleEndian>(v, nbytes),
+                Endianness::Big => src.$method::<BigEndian>(v, nbytes),
+            }
+        }
+    };
+}
+
+/// Private macro for endiannesses known at run time,
+/// which implements a `write_*_into` method
+/// by encoding all values with the same method on `ByteOrder`
+/// and writing the resulting bytes at once.
+macro_rules! fn_runtime_endianness_write_into {
+    ($method:ident, $i:ty) => {
+        #[inline]
+        fn $method<W>(self, mut dst: W, src: &[$i]) -> IoResult<()>
+        where
+            W: Write,
+        {
+            let mut buf = vec![0; src.len() * size_of::<$i>()];
+            match self {
+                Endianness::Little => LittleEndian::$method(src, &mut buf),
+                Endianness::Big => BigEndian::$method(src, &mut buf),
+            }
+            dst.write_all(&buf)
+        }
+    };
+}
+
+/// Private macro for endiannesses known at run time,
+/// which implements a `write_*24` method
+/// by checking that the value fits in 24 bits
+/// and delegating a call to the same method on `WriteBytesExt`.
+macro_rules! fn_runtime_endianness_write_24 {
+    ($method:ident, $i:ty, $range:expr) => {
+        #[inline]
+        fn $method<S>(self, mut src: S, v: $i) -> IoResult<()>
+        where
+            S: Write,
+        {
+            if !$range.contains(&v) {
+                return Err(not_24_bit(v));
+            }
+            match self {
+                Endianness::Little => src.$method::<LittleEndian>(v),
+                Endianness::Big => src.$method::<BigEndian>(v),
+            }
+        }
+    };
+}
+
 impl HasOpposite for Endianness {
     type Opposite = Self;
 }
@@ -781,6 +1580,8 @@ impl HasOpposite for Endianness {
 impl Endian for Endianness {
     type Opposite = Self;
 
+    const DEFAULT: Self = Endianness::native_const();
+
     #[inline]
     fn into_opposite(self) -> Self::Opposite {
         self.to_opposite()
@@ -793,6 +1594,8 @@ impl Endian for Endianness {
 
     fn_runtime_endianness_read!(read_i16, i16);
     fn_runtime_endianness_read!(read_u16, u16);
+    fn_runtime_endianness_read!(read_i24, i32);
+    fn_runtime_endianness_read!(read_u24, u32);
     fn_runtime_endianness_read!(read_i32, i32);
     fn_runtime_endianness_read!(read_u32, u32);
     fn_runtime_endianness_read!(read_i64, i64);
@@ -802,6 +1605,11 @@ impl Endian for Endianness {
     fn_runtime_endianness_read!(read_i128, i128);
     fn_runtime_endianness_read!(read_u128, u128);
 
+    fn_runtime_endianness_read_n!(read_int, i64);
+    fn_runtime_endianness_read_n!(read_uint, u64);
+    fn_runtime_endianness_read_n!(read_int128, i128);
+    fn_runtime_endianness_read_n!(read_uint128, u128);
+
     fn_runtime_endianness_read_into!(read_i16_into, i16);
     fn_runtime_endianness_read_into!(read_u16_into, u16);
     fn_runtime_endianness_read_into!(read_i32_into, i32);
@@ -815,6 +1623,8 @@ impl Endian for Endianness {
 
     fn_runtime_endianness_write!(write_i16, i16);
     fn_runtime_endianness_write!(write_u16, u16);
+    fn_runtime_endianness_write_24!(write_i24, i32, I24_RANGE);
+    fn_runtime_endianness_write_24!(write_u24, u32, U24_RANGE);
     fn_runtime_endianness_write!(write_i32, i32);
     fn_runtime_endianness_write!(write_u32, u32);
     fn_runtime_endianness_write!(write_i64, i64);
@@ -823,6 +1633,37 @@ impl Endian for Endianness {
     fn_runtime_endianness_write!(write_f64, f64);
     fn_runtime_endianness_write!(write_i128, i128);
     fn_runtime_endianness_write!(write_u128, u128);
+
+    fn_runtime_endianness_write_n!(write_int, i64);
+    fn_runtime_endianness_write_n!(write_uint, u64);
+    fn_runtime_endianness_write_n!(write_int128, i128);
+    fn_runtime_endianness_write_n!(write_uint128, u128);
+
+    fn_runtime_endianness_write_into!(write_i16_into, i16);
+    fn_runtime_endianness_write_into!(write_u16_into, u16);
+    fn_runtime_endianness_write_into!(write_i32_into, i32);
+    fn_runtime_endianness_write_into!(write_u32_into, u32);
+    fn_runtime_endianness_write_into!(write_i64_into, i64);
+    fn_runtime_endianness_write_into!(write_u64_into, u64);
+    fn_runtime_endianness_write_into!(write_i128_into, i128);
+    fn_runtime_endianness_write_into!(write_u128_into, u128);
+    fn_runtime_endianness_write_into!(write_f32_into, f32);
+    fn_runtime_endianness_write_into!(write_f64_into, f64);
+}
+
+/// Private macro for comparing two encoded unsigned integers
+/// without decoding them.
+macro_rules! fn_compare_bytes {
+    ($(#[$attr:meta])* $method:ident, $n:expr) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method(self, a: [u8; $n], b: [u8; $n]) -> Ordering {
+            match self {
+                Endianness::Big => a.cmp(&b),
+                Endianness::Little => a.iter().rev().cmp(b.iter().rev()),
+            }
+        }
+    };
 }
 
 impl Endianness {
@@ -844,6 +1685,50 @@ impl Endianness {
         Endianness::Big
     }
 
+    /// Obtains this system's native endianness in a constant context.
+    ///
+    /// This is equivalent to [`native`],
+    /// but can be used to initialize `const` and `static` items.
+    ///
+    /// On this platform, the function returns `Endianness::Little`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byteordered::Endianness;
+    /// const NATIVE: Endianness = Endianness::native_const();
+    /// assert_eq!(NATIVE, Endianness::native());
+    /// ```
+    ///
+    /// [`native`]: #method.native
+    #[cfg(target_endian = "little")]
+    #[inline]
+    pub const fn native_const() -> Self {
+        Endianness::Little
+    }
+
+    /// Obtains this system's native endianness in a constant context.
+    ///
+    /// This is equivalent to [`native`],
+    /// but can be used to initialize `const` and `static` items.
+    ///
+    /// On this platform, the function returns `Endianness::Big`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byteordered::Endianness;
+    /// const NATIVE: Endianness = Endianness::native_const();
+    /// assert_eq!(NATIVE, Endianness::native());
+    /// ```
+    ///
+    /// [`native`]: #method.native
+    #[cfg(target_endian = "big")]
+    #[inline]
+    pub const fn native_const() -> Self {
+        Endianness::Big
+    }
+
     /// Obtains _Little Endian_ if and only if the given value is `true`.
     ///
     /// # Examples
@@ -884,6 +1769,72 @@ impl Endianness {
         }
     }
 
+    /// Obtains the byte order indicated by the two bytes
+    /// of a UTF-16 byte order mark (`U+FEFF`).
+    ///
+    /// Returns `None` if the bytes are not a byte order mark.
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// # use byteordered::Endianness;
+    /// assert_eq!(Endianness::from_u16_bom([0xFE, 0xFF]), Some(Endianness::Big));
+    /// assert_eq!(Endianness::from_u16_bom([0xFF, 0xFE]), Some(Endianness::Little));
+    /// assert_eq!(Endianness::from_u16_bom([0x00, 0x41]), None);
+    /// ```
+    #[inline]
+    pub fn from_u16_bom(bytes: [u8; 2]) -> Option<Self> {
+        match bytes {
+            [0xFE, 0xFF] => Some(Endianness::Big),
+            [0xFF, 0xFE] => Some(Endianness::Little),
+            _ => None,
+        }
+    }
+
+    fn_compare_bytes!(
+        /// Compares two encoded unsigned 16 bit integers
+        /// as if they were decoded in this byte order,
+        /// without constructing the integers.
+        compare_u16_bytes,
+        2
+    );
+
+    fn_compare_bytes!(
+        /// Compares two encoded unsigned 32 bit integers
+        /// as if they were decoded in this byte order,
+        /// without constructing the integers.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use byteordered::Endianness;
+        /// use std::cmp::Ordering;
+        ///
+        /// let a = [0x01, 0x00, 0x00, 0x02];
+        /// let b = [0x02, 0x00, 0x00, 0x01];
+        /// assert_eq!(Endianness::Big.compare_u32_bytes(a, b), Ordering::Less);
+        /// assert_eq!(Endianness::Little.compare_u32_bytes(a, b), Ordering::Greater);
+        /// ```
+        compare_u32_bytes,
+        4
+    );
+
+    fn_compare_bytes!(
+        /// Compares two encoded unsigned 64 bit integers
+        /// as if they were decoded in this byte order,
+        /// without constructing the integers.
+        compare_u64_bytes,
+        8
+    );
+
+    fn_compare_bytes!(
+        /// Compares two encoded unsigned 128 bit integers
+        /// as if they were decoded in this byte order,
+        /// without constructing the integers.
+        compare_u128_bytes,
+        16
+    );
+
     /// Obtains the opposite endianness: Little Endian returns Big Endian and vice versa.
     #[inline]
     pub fn to_opposite(self) -> Self {
@@ -893,6 +1844,60 @@ impl Endianness {
             Endianness::Little
         }
     }
+
+    /// Checks whether this is _Little Endian_.
+    #[inline]
+    pub fn is_little(self) -> bool {
+        self == Endianness::Little
+    }
+
+    /// Checks whether this is _Big Endian_.
+    #[inline]
+    pub fn is_big(self) -> bool {
+        self == Endianness::Big
+    }
+
+    /// Picks one of the given values depending on this byte order.
+    ///
+    /// This is a replacement for a `match` on the two byte orders.
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// # use byteordered::Endianness;
+    /// let e = Endianness::Big;
+    /// assert_eq!(e.select("LE", "BE"), "BE");
+    /// ```
+    #[inline]
+    pub fn select<T>(self, little: T, big: T) -> T {
+        self.fold(|| little, || big)
+    }
+
+    /// Calls one of the given functions depending on this byte order,
+    /// returning its outcome.
+    ///
+    /// Unlike [`select`](#method.select),
+    /// only the value for the actual byte order is produced.
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// # use byteordered::Endianness;
+    /// let e = Endianness::Little;
+    /// let name = e.fold(|| "little".to_string(), || "big".to_string());
+    /// assert_eq!(name, "little");
+    /// ```
+    #[inline]
+    pub fn fold<T, L, B>(self, little: L, big: B) -> T
+    where
+        L: FnOnce() -> T,
+        B: FnOnce() -> T,
+    {
+        match self {
+            Endianness::Little => little(),
+            Endianness::Big => big(),
+        }
+    }
 }
 
 mod private {
@@ -910,12 +1915,12 @@ mod private {
 mod tests {
     use super::*;
     /// the test bytes for testing integer type reading
-    static TEST_BYTES: &'static [u8] = &[0x12, 0x34, 0x56, 0x78, 0x21, 0x43, 0x65, 0x87];
+    static TEST_BYTES: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x21, 0x43, 0x65, 0x87];
 
     /// the test bytes as a single u64 in little endian
-    static TEST_U64DATA_LE: &'static [u64] = &[0x87654321_78563412];
+    static TEST_U64DATA_LE: &[u64] = &[0x87654321_78563412];
     /// the test bytes as a single u64 in big endian
-    static TEST_U64DATA_BE: &'static [u64] = &[0x12345678_21436587];
+    static TEST_U64DATA_BE: &[u64] = &[0x12345678_21436587];
 
     #[test]
     fn test_read_u64() {
@@ -931,9 +1936,9 @@ mod tests {
     }
 
     /// the test bytes as two u32s in little endian
-    static TEST_U32DATA_LE: &'static [u32] = &[0x7856_3412, 0x8765_4321];
+    static TEST_U32DATA_LE: &[u32] = &[0x7856_3412, 0x8765_4321];
     /// the test bytes as two u32s in big endian
-    static TEST_U32DATA_BE: &'static [u32] = &[0x1234_5678, 0x2143_6587];
+    static TEST_U32DATA_BE: &[u32] = &[0x1234_5678, 0x2143_6587];
 
     #[test]
     fn test_read_u32() {
@@ -955,9 +1960,9 @@ mod tests {
     }
 
     /// the test bytes as four u16s in little endian
-    static TEST_U16DATA_LE: &'static [u16] = &[0x3412, 0x7856, 0x4321, 0x8765];
+    static TEST_U16DATA_LE: &[u16] = &[0x3412, 0x7856, 0x4321, 0x8765];
     /// the test bytes as four u16s in big endian
-    static TEST_U16DATA_BE: &'static [u16] = &[0x1234, 0x5678, 0x2143, 0x6587];
+    static TEST_U16DATA_BE: &[u16] = &[0x1234, 0x5678, 0x2143, 0x6587];
 
     #[test]
     fn test_read_u16() {
@@ -1012,6 +2017,81 @@ mod tests {
         assert_eq!(words, TEST_U32DATA_BE);
     }
 
+    /// A reader which counts the calls to `read`.
+    struct CountingReader<'a> {
+        data: &'a [u8],
+        reads: usize,
+    }
+
+    impl<'a> Read for CountingReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+            self.reads += 1;
+            self.data.read(buf)
+        }
+    }
+
+    #[test]
+    fn test_runtime_read_into_single_read() {
+        let data: Vec<u8> = (0..64).collect();
+        for &e in &[Endianness::Little, Endianness::Big] {
+            macro_rules! check_single_read {
+                ($method:ident, $read:ident, $ty:ty, $n:expr, $size:expr) => {
+                    let mut reader = CountingReader {
+                        data: &data,
+                        reads: 0,
+                    };
+                    let mut values = [<$ty>::default(); $n];
+                    e.$method(&mut reader, &mut values).unwrap();
+                    assert_eq!(reader.reads, 1, stringify!($method));
+                    assert_eq!(values[$n - 1], e.$read(&data[($n - 1) * $size..]).unwrap());
+                };
+            }
+            check_single_read!(read_u16_into, read_u16, u16, 32, 2);
+            check_single_read!(read_i16_into, read_i16, i16, 32, 2);
+            check_single_read!(read_u32_into, read_u32, u32, 16, 4);
+            check_single_read!(read_i32_into, read_i32, i32, 16, 4);
+            check_single_read!(read_u64_into, read_u64, u64, 8, 8);
+            check_single_read!(read_i64_into, read_i64, i64, 8, 8);
+            check_single_read!(read_u128_into, read_u128, u128, 4, 16);
+            check_single_read!(read_i128_into, read_i128, i128, 4, 16);
+            check_single_read!(read_f32_into, read_f32, f32, 16, 4);
+            check_single_read!(read_f64_into, read_f64, f64, 8, 8);
+        }
+    }
+
+    #[test]
+    fn test_select_and_fold() {
+        let le = Endianness::Little;
+        let be = Endianness::Big;
+        assert!(le.is_little() && !le.is_big());
+        assert!(be.is_big() && !be.is_little());
+        assert_eq!(le.select(1, 2), 1);
+        assert_eq!(be.select(1, 2), 2);
+        assert_eq!(le.fold(|| 'l', || unreachable!()), 'l');
+        assert_eq!(be.fold(|| unreachable!(), || 'b'), 'b');
+        for &e in &[le, be] {
+            assert_eq!(e.to_opposite().select(be, le), e);
+        }
+    }
+
+    #[test]
+    fn test_endianness_layout() {
+        assert_eq!(::std::mem::size_of::<Endianness>(), 1);
+        assert_eq!(::std::mem::size_of::<Option<Endianness>>(), 1);
+        assert_eq!(Endianness::Little as u8, 0);
+        assert_eq!(Endianness::Big as u8, 1);
+    }
+
+    #[test]
+    fn test_endianness_try_from_u8() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            assert_eq!(Endianness::try_from(e as u8), Ok(e));
+        }
+        let e = Endianness::try_from(0xFF).unwrap_err();
+        assert_eq!(e.tag(), 0xFF);
+        assert_eq!(e.to_string(), "invalid byte order tag 255");
+    }
+
     #[test]
     fn test_native_is_le() {
         if cfg!(target_endian = "little") {
@@ -1023,5 +2103,302 @@ mod tests {
         }
     }
 
+    /// Obtains a byte order value in generic code.
+    fn default_of<E: Endian>() -> E {
+        E::DEFAULT
+    }
+
+    /// Reads a tag byte followed by a 16 bit value,
+    /// through the `Endian` trait alone.
+    fn read_tagged<E: Endian>(e: E, mut data: &[u8]) -> (u8, i8, u16) {
+        let tag = e.read_u8(&mut data).unwrap();
+        let signed = e.read_i8(&mut data).unwrap();
+        (tag, signed, e.read_u16(&mut data).unwrap())
+    }
+
+    #[test]
+    fn test_endian_read_bytes() {
+        let data = [0x07, 0xFE, 0x01, 0x02];
+        assert_eq!(read_tagged(Endianness::Little, &data), (7, -2, 0x0201));
+        assert_eq!(read_tagged(Endianness::Big, &data), (7, -2, 0x0102));
+        let le = StaticEndianness::<LittleEndian>::default();
+        assert_eq!(read_tagged(le, &data), (7, -2, 0x0201));
+        let be = StaticEndianness::<BigEndian>::default();
+        assert_eq!(read_tagged(be, &data), (7, -2, 0x0102));
+        assert!(be.read_u8(&[][..]).is_err());
+    }
+
+    /// Writes a tag byte followed by a 16 bit value,
+    /// through the `Endian` trait alone.
+    fn write_tagged<E: Endian>(e: E, tag: u8, signed: i8, value: u16) -> Vec<u8> {
+        let mut data = Vec::new();
+        e.write_u8(&mut data, tag).unwrap();
+        e.write_i8(&mut data, signed).unwrap();
+        e.write_u16(&mut data, value).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_endian_write_bytes() {
+        let le = StaticEndianness::<LittleEndian>::default();
+        let be = StaticEndianness::<BigEndian>::default();
+        assert_eq!(write_tagged(le, 7, -2, 0x0102), [0x07, 0xFE, 0x02, 0x01]);
+        assert_eq!(write_tagged(be, 7, -2, 0x0102), [0x07, 0xFE, 0x01, 0x02]);
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let data = write_tagged(e, 0x80, i8::MIN, 0xABCD);
+            assert_eq!(read_tagged(e, &data), (0x80, i8::MIN, 0xABCD));
+        }
+    }
+
+    /// Writes and reads back 24 bit integers through the `Endian` trait.
+    fn round_trip_24<E: Endian>(e: E, expected: &[u8]) {
+        let mut data = Vec::new();
+        e.write_u24(&mut data, 0x12_3456).unwrap();
+        e.write_i24(&mut data, -2).unwrap();
+        e.write_i24(&mut data, -0x80_0000).unwrap();
+        assert_eq!(&data[..3], expected);
+        let mut reader = &data[..];
+        assert_eq!(e.read_u24(&mut reader).unwrap(), 0x12_3456);
+        assert_eq!(e.read_i24(&mut reader).unwrap(), -2);
+        assert_eq!(e.read_i24(&mut reader).unwrap(), -0x80_0000);
+
+        // values out of range are rejected without writing anything
+        let mut data = Vec::new();
+        let err = e.write_u24(&mut data, 0x100_0000).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let err = e.write_i24(&mut data, 0x80_0000).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_endian_24_bit() {
+        let le = [0x56, 0x34, 0x12];
+        let be = [0x12, 0x34, 0x56];
+        round_trip_24(StaticEndianness::<LittleEndian>::default(), &le);
+        round_trip_24(StaticEndianness::<BigEndian>::default(), &be);
+        round_trip_24(Endianness::Little, &le);
+        round_trip_24(Endianness::Big, &be);
+    }
+
+    /// Reads variable-width integers through the `Endian` trait.
+    fn read_n<E: Endian>(e: E, data: &[u8]) -> (u64, i64) {
+        let mut reader = data;
+        let u = e.read_uint(&mut reader, 3).unwrap();
+        let i = e.read_int(&mut reader, 5).unwrap();
+        assert!(reader.is_empty());
+        (u, i)
+    }
+
+    #[test]
+    fn test_endian_read_n() {
+        let data = [0x01, 0x02, 0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE];
+        let le = (0x03_0201, -0x1_0000_0001);
+        let be = (0x01_0203, -2);
+        assert_eq!(
+            read_n(StaticEndianness::<LittleEndian>::default(), &data),
+            le
+        );
+        assert_eq!(read_n(StaticEndianness::<BigEndian>::default(), &data), be);
+        assert_eq!(read_n(Endianness::Little, &data), le);
+        assert_eq!(read_n(Endianness::Big, &data), be);
+
+        let mut reader = &data[..];
+        assert_eq!(
+            Endianness::Big.read_uint(&mut reader, 8).unwrap(),
+            0x0102_03FF_FFFF_FFFE
+        );
+        let mut reader = &data[..2];
+        let err = Endianness::Big.read_uint(&mut reader, 3).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    /// Writes variable-width integers through the `Endian` trait.
+    fn write_n<E: Endian>(e: E) -> Vec<u8> {
+        let mut data = Vec::new();
+        e.write_uint(&mut data, 0x03_0201, 3).unwrap();
+        e.write_int(&mut data, -2, 5).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_endian_write_n() {
+        let le = [0x01, 0x02, 0x03, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF];
+        let be = [0x03, 0x02, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE];
+        assert_eq!(write_n(StaticEndianness::<LittleEndian>::default()), le);
+        assert_eq!(write_n(StaticEndianness::<BigEndian>::default()), be);
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let data = write_n(e);
+            assert_eq!(read_n(e, &data), (0x03_0201, -2));
+        }
+    }
+
+    /// Writes and reads back 128 bit variable-width integers
+    /// through the `Endian` trait.
+    fn round_trip_n128<E: Endian>(e: E) -> Vec<u8> {
+        let guid = 0x0011_2233_4455_6677_8899_AABB_CCDD_EEFF;
+        let mut data = Vec::new();
+        e.write_uint128(&mut data, guid, 16).unwrap();
+        e.write_int128(&mut data, -3, 11).unwrap();
+        e.write_uint128(&mut data, 0xAB_CDEF, 9).unwrap();
+        assert_eq!(data.len(), 36);
+        let mut reader = &data[..];
+        assert_eq!(e.read_uint128(&mut reader, 16).unwrap(), guid);
+        assert_eq!(e.read_int128(&mut reader, 11).unwrap(), -3);
+        assert_eq!(e.read_uint128(&mut reader, 9).unwrap(), 0xAB_CDEF);
+        data
+    }
+
+    #[test]
+    fn test_endian_n128() {
+        let le = round_trip_n128(StaticEndianness::<LittleEndian>::default());
+        let be = round_trip_n128(StaticEndianness::<BigEndian>::default());
+        assert_eq!(&le[27..], &[0xEF, 0xCD, 0xAB, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(&be[27..], &[0, 0, 0, 0, 0, 0, 0xAB, 0xCD, 0xEF]);
+        assert_eq!(round_trip_n128(Endianness::Little), le);
+        assert_eq!(round_trip_n128(Endianness::Big), be);
+    }
+
+    #[test]
+    fn test_endian_write_int_truncates() {
+        let mut data = Vec::new();
+        Endianness::Big.write_int(&mut data, -1, 2).unwrap();
+        StaticEndianness::<LittleEndian>::default()
+            .write_int(&mut data, 0x12_3456, 2)
+            .unwrap();
+        assert_eq!(data, [0xFF, 0xFF, 0x56, 0x34]);
+    }
+
+    #[test]
+    fn test_endian_write_int128_truncates() {
+        let mut data = Vec::new();
+        Endianness::Little
+            .write_int128(&mut data, i128::MIN + 0xABCD, 3)
+            .unwrap();
+        assert_eq!(data, [0xCD, 0xAB, 0x00]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_endian_write_n_too_narrow() {
+        let _ = Endianness::Big.write_uint(Vec::new(), 0x1_0000, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_endian_read_n_too_wide() {
+        let _ = Endianness::Little.read_uint(&[0; 16][..], 9);
+    }
+
+    #[test]
+    fn test_endian_write_into() {
+        let le = StaticEndianness::<LittleEndian>::default();
+        let be = StaticEndianness::<BigEndian>::default();
+        let mut data = Vec::new();
+        le.write_u16_into(&mut data, &[0x0102, 0x0304]).unwrap();
+        be.write_u16_into(&mut data, &[0x0102, 0x0304]).unwrap();
+        assert_eq!(data, [2, 1, 4, 3, 1, 2, 3, 4]);
+
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut data = Vec::new();
+            e.write_i32_into(&mut data, &[-1, 7]).unwrap();
+            e.write_f64_into(&mut data, &[0.5, -1e300]).unwrap();
+            e.write_u128_into(&mut data, &[u128::MAX - 1]).unwrap();
+            e.write_i16_into(&mut data, &[]).unwrap();
+            assert_eq!(data.len(), 8 + 16 + 16);
+
+            let mut reader = &data[..];
+            let mut ints = [0; 2];
+            e.read_i32_into(&mut reader, &mut ints).unwrap();
+            assert_eq!(ints, [-1, 7]);
+            let mut floats = [0.; 2];
+            e.read_f64_into(&mut reader, &mut floats).unwrap();
+            assert_eq!(floats, [0.5, -1e300]);
+            assert_eq!(e.read_u128(&mut reader).unwrap(), u128::MAX - 1);
+        }
+    }
+
+    #[test]
+    fn test_default_endian() {
+        let le: StaticEndianness<LittleEndian> = default_of();
+        let be: StaticEndianness<BigEndian> = default_of();
+        assert_eq!(le.read_u16(&[1, 0][..]).unwrap(), 1);
+        assert_eq!(be.read_u16(&[1, 0][..]).unwrap(), 256);
+        assert!(default_of::<Endianness>().is_native());
+    }
+
+    /// Checks that every way of resolving the native byte order agrees
+    /// with the expected endianness of the current target.
+    fn assert_native_consistency(expected: Endianness) {
+        const NATIVE: Endianness = Endianness::native_const();
+        assert_eq!(Endianness::native(), expected);
+        assert_eq!(NATIVE, expected);
+        assert_eq!(
+            Endianness::from(StaticEndianness::<NativeEndian>::native()),
+            expected
+        );
+        assert!(StaticEndianness::<NativeEndian>::native().is_native());
+        assert!(StaticEndianness::<NativeEndian>::default() == expected);
+        assert!(expected == StaticEndianness::<NativeEndian>::default());
+        assert!(StaticEndianness::<NativeEndian>::default() != expected.to_opposite());
+        assert!(expected.to_opposite() != StaticEndianness::<NativeEndian>::default());
+        assert!(expected.is_native());
+        assert!(!expected.to_opposite().is_native());
+        assert_eq!(default_of::<Endianness>(), expected);
+        assert!(default_of::<StaticEndianness<NativeEndian>>() == expected);
+    }
+
+    #[test]
+    fn test_native_consistency() {
+        if cfg!(target_endian = "little") {
+            assert_native_consistency(Endianness::Little);
+        } else if cfg!(target_endian = "big") {
+            assert_native_consistency(Endianness::Big);
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn test_compare_bytes() {
+        let samples: &[[u8; 4]] = &[
+            [0x00, 0x00, 0x00, 0x00],
+            [0x01, 0x00, 0x00, 0x02],
+            [0x02, 0x00, 0x00, 0x01],
+            [0xFF, 0x00, 0x00, 0x00],
+            [0x00, 0x00, 0x00, 0xFF],
+            [0x12, 0x34, 0x56, 0x78],
+            [0xFF, 0xFF, 0xFF, 0xFF],
+        ];
+        for &e in &[Endianness::Little, Endianness::Big] {
+            for a in samples {
+                for b in samples {
+                    let expected = e
+                        .read_u32(&a[..])
+                        .unwrap()
+                        .cmp(&e.read_u32(&b[..]).unwrap());
+                    assert_eq!(e.compare_u32_bytes(*a, *b), expected);
+                }
+            }
+        }
+
+        let a = [0x01, 0x00];
+        let b = [0x00, 0x01];
+        assert_eq!(Endianness::Big.compare_u16_bytes(a, b), Ordering::Greater);
+        assert_eq!(Endianness::Little.compare_u16_bytes(a, b), Ordering::Less);
+
+        let a = 0x0102_0304_0506_0708_u64;
+        let b = 0x0807_0605_0403_0201_u64;
+        assert_eq!(
+            Endianness::Little.compare_u64_bytes(a.to_le_bytes(), b.to_le_bytes()),
+            Ordering::Less
+        );
+        assert_eq!(
+            Endianness::Big
+                .compare_u128_bytes(u128::from(a).to_be_bytes(), u128::from(b).to_be_bytes()),
+            Ordering::Less
+        );
+    }
+
     // TODO test writing
 }
@@ -0,0 +1,97 @@
+//! Reading of sequences with a known upper bound
+//! into `ArrayVec`s from the `arrayvec` crate.
+
+use arrayvec::ArrayVec;
+use byteorder::ReadBytesExt;
+use std::io::{Error as IoError, ErrorKind, Result as IoResult};
+use {ByteOrdered, Endian};
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: ReadBytesExt,
+    E: Endian,
+{
+    /// Reads `n` unsigned 32 bit integers from the underlying reader
+    /// into a stack allocated vector with a capacity of `N`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without reading anything
+    /// if `n` is greater than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate arrayvec;
+    /// # extern crate byteordered;
+    /// use arrayvec::ArrayVec;
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0, 0, 0, 1, 0, 0, 0, 2][..]);
+    /// let values: ArrayVec<u32, 16> = rdr.read_u32_into_arrayvec(2)?;
+    /// assert_eq!(&values[..], &[1, 2]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn read_u32_into_arrayvec<const N: usize>(
+        &mut self,
+        n: usize,
+    ) -> IoResult<ArrayVec<u32, N>> {
+        if n > N {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("cannot read {} values into a capacity of {}", n, N),
+            ));
+        }
+        let mut data = ArrayVec::from([0; N]);
+        self.read_u32_into(&mut data[..n])?;
+        data.truncate(n);
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrayvec::ArrayVec;
+    use base::Endianness;
+    use std::io::ErrorKind;
+    use wrap::ByteOrdered;
+
+    #[test]
+    fn test_read_u32_into_arrayvec() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            for v in 1..=4 {
+                writer.write_u32(v).unwrap();
+            }
+            let data = writer.into_inner();
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let values: ArrayVec<u32, 4> = reader.read_u32_into_arrayvec(3).unwrap();
+            assert_eq!(&values[..], &[1, 2, 3]);
+            assert_eq!(values.capacity(), 4);
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let values: ArrayVec<u32, 4> = reader.read_u32_into_arrayvec(4).unwrap();
+            assert_eq!(&values[..], &[1, 2, 3, 4]);
+            assert!(values.is_full());
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let err = reader.read_u32_into_arrayvec::<2>(3).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            // nothing was consumed
+            assert_eq!(reader.into_inner().len(), 16);
+
+            let mut reader = ByteOrdered::runtime(&data[..6], e);
+            let err = reader.read_u32_into_arrayvec::<4>(2).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        }
+    }
+}
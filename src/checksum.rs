@@ -0,0 +1,396 @@
+//! Adapter module for computing checksums of read or written data.
+
+use std::io::{Read, Result as IoResult, Write};
+use {ByteOrdered, Endian};
+
+/// Lookup table for the CRC-32 (IEEE 802.3) polynomial, in reflected form.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+/// Writer adapter which computes a running CRC-32 checksum
+/// of all bytes written through it.
+///
+/// The checksum is the widespread CRC-32 variant
+/// (IEEE 802.3, as used by zlib, PNG and ZIP).
+/// Only bytes accepted by the inner writer are accounted for.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{ByteOrdered, Crc32Writer};
+/// use std::io::Write;
+///
+/// # fn run() -> std::io::Result<()> {
+/// let mut wtr = ByteOrdered::be(Crc32Writer::new(Vec::new()));
+/// wtr.write_all(b"123456789")?;
+/// assert_eq!(wtr.inner_mut().crc32(), 0xCBF4_3926);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Crc32Writer<W> {
+    inner: W,
+    state: u32,
+}
+
+impl<W> Crc32Writer<W> {
+    /// Wraps the given writer, starting with an empty checksum.
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        Crc32Writer {
+            inner,
+            state: 0xFFFF_FFFF,
+        }
+    }
+
+    /// Retrieves the checksum of the data written so far.
+    #[inline]
+    pub fn crc32(&self) -> u32 {
+        !self.state
+    }
+
+    /// Obtains a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Obtains a mutable reference to the inner writer.
+    ///
+    /// Bytes written directly to the inner writer
+    /// are not accounted for in the checksum.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Recovers the inner writer,
+    /// discarding the checksum.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn update(&mut self, buf: &[u8]) {
+        for &b in buf {
+            self.state =
+                CRC32_TABLE[((self.state ^ u32::from(b)) & 0xFF) as usize] ^ (self.state >> 8);
+        }
+    }
+}
+
+impl<W> Write for Crc32Writer<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let n = self.inner.write(buf)?;
+        self.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W, E> ByteOrdered<W, E>
+where
+    E: Endian,
+{
+    /// Wraps the inner writer in a [`Crc32Writer`] adapter,
+    /// so that a checksum of all data written from now on
+    /// can be appended with [`finish_with_crc32`].
+    ///
+    /// [`Crc32Writer`]: struct.Crc32Writer.html
+    /// [`finish_with_crc32`]: #method.finish_with_crc32
+    #[inline]
+    pub fn with_crc32(self) -> ByteOrdered<Crc32Writer<W>, E> {
+        self.map(Crc32Writer::new)
+    }
+}
+
+impl<W, E> ByteOrdered<Crc32Writer<W>, E>
+where
+    W: Write,
+    E: Endian,
+{
+    /// Writes the CRC-32 checksum of the data written so far
+    /// as an unsigned 32 bit integer in the assumed byte order,
+    /// and returns the inner writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Write;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut wtr = ByteOrdered::le(Vec::new()).with_crc32();
+    /// wtr.write_all(b"123456789")?;
+    /// let data = wtr.finish_with_crc32()?;
+    /// assert_eq!(&data[9..], &[0x26, 0x39, 0xF4, 0xCB]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    pub fn finish_with_crc32(self) -> IoResult<W> {
+        let (adapter, e) = self.into_parts();
+        let crc = adapter.crc32();
+        let mut inner = adapter.into_inner();
+        e.write_u32(&mut inner, crc)?;
+        Ok(inner)
+    }
+}
+
+/// Reader and writer adapter which computes a running Internet checksum
+/// of all bytes read or written through it.
+///
+/// The Internet checksum (RFC 1071) is the 16 bit one's complement
+/// of the one's complement sum of the data,
+/// taken as big endian 16 bit words,
+/// as used in the headers of IPv4, TCP, UDP and ICMP.
+/// Data of odd length is padded with a zero byte,
+/// and data may be fed in chunks of any length.
+/// Only bytes returned by the inner reader
+/// or accepted by the inner writer are accounted for.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::ByteOrdered;
+///
+/// # fn run() -> std::io::Result<()> {
+/// let mut wtr = ByteOrdered::be(Vec::new()).internet_checksum();
+/// wtr.write_u32(0x0001_F203)?;
+/// wtr.write_u32(0xF4F5_F6F7)?;
+/// assert_eq!(wtr.inner_mut().checksum(), 0x220D);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct InternetChecksum<T> {
+    inner: T,
+    sum: u32,
+    odd: Option<u8>,
+}
+
+impl<T> InternetChecksum<T> {
+    /// Wraps the given reader or writer, starting with an empty checksum.
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        InternetChecksum {
+            inner,
+            sum: 0,
+            odd: None,
+        }
+    }
+
+    /// Retrieves the checksum of the data read or written so far.
+    ///
+    /// A trailing odd byte is padded with a zero byte,
+    /// without affecting the data which may follow.
+    pub fn checksum(&self) -> u16 {
+        let sum = match self.odd {
+            Some(b) => fold_ones_complement(self.sum + (u32::from(b) << 8)),
+            None => self.sum,
+        };
+        !(sum as u16)
+    }
+
+    /// Obtains a reference to the inner reader or writer.
+    #[inline]
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Obtains a mutable reference to the inner reader or writer.
+    ///
+    /// Bytes read or written directly through the inner value
+    /// are not accounted for in the checksum.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Recovers the inner reader or writer,
+    /// discarding the checksum.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn update(&mut self, mut buf: &[u8]) {
+        if let Some(hi) = self.odd.take() {
+            match buf.split_first() {
+                Some((&lo, rest)) => {
+                    self.add_word(hi, lo);
+                    buf = rest;
+                }
+                None => {
+                    self.odd = Some(hi);
+                    return;
+                }
+            }
+        }
+        let mut words = buf.chunks_exact(2);
+        for w in &mut words {
+            self.add_word(w[0], w[1]);
+        }
+        self.odd = words.remainder().first().copied();
+    }
+
+    #[inline]
+    fn add_word(&mut self, hi: u8, lo: u8) {
+        self.sum = fold_ones_complement(self.sum + u32::from(u16::from_be_bytes([hi, lo])));
+    }
+}
+
+/// Folds the carry of a sum of two 16 bit words back into it.
+#[inline]
+fn fold_ones_complement(sum: u32) -> u32 {
+    (sum & 0xFFFF) + (sum >> 16)
+}
+
+impl<R> Read for InternetChecksum<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let n = self.inner.read(buf)?;
+        self.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+impl<W> Write for InternetChecksum<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let n = self.inner.write(buf)?;
+        self.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
+
+impl<T, E> ByteOrdered<T, E>
+where
+    E: Endian,
+{
+    /// Wraps the inner reader or writer in an [`InternetChecksum`] adapter,
+    /// so that the Internet checksum of all data
+    /// read or written from now on can be retrieved.
+    ///
+    /// The checksum does not depend on the assumed byte order.
+    ///
+    /// [`InternetChecksum`]: struct.InternetChecksum.html
+    #[inline]
+    pub fn internet_checksum(self) -> ByteOrdered<InternetChecksum<T>, E> {
+        self.map(InternetChecksum::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Crc32Writer, InternetChecksum};
+    use base::Endianness;
+    use std::io::{Read, Write};
+    use wrap::ByteOrdered;
+
+    #[test]
+    fn test_crc32() {
+        let mut writer = Crc32Writer::new(Vec::new());
+        assert_eq!(writer.crc32(), 0);
+        writer.write_all(b"The quick brown fox ").unwrap();
+        writer.write_all(b"jumps over the lazy dog").unwrap();
+        assert_eq!(writer.crc32(), 0x414F_A339);
+    }
+
+    #[test]
+    fn test_finish_with_crc32() {
+        for &(e, trailer) in &[
+            (Endianness::Little, [0x26, 0x39, 0xF4, 0xCB]),
+            (Endianness::Big, [0xCB, 0xF4, 0x39, 0x26]),
+        ] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e).with_crc32();
+            writer.write_all(b"1234").unwrap();
+            writer.write_all(b"56789").unwrap();
+            let data = writer.finish_with_crc32().unwrap();
+            assert_eq!(&data[..9], b"123456789");
+            assert_eq!(&data[9..], &trailer);
+        }
+
+        // only data written after wrapping is accounted for
+        let mut writer = ByteOrdered::be(Vec::new());
+        writer.write_u16(0xFFFF).unwrap();
+        let mut writer = writer.with_crc32();
+        writer.write_all(b"123456789").unwrap();
+        let data = writer.finish_with_crc32().unwrap();
+        assert_eq!(&data[11..], &[0xCB, 0xF4, 0x39, 0x26]);
+    }
+
+    #[test]
+    fn test_internet_checksum() {
+        // the sample IPv4 header from the Wikipedia article,
+        // with the checksum field zeroed
+        let header: [u8; 20] = [
+            0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0xC0, 0xA8,
+            0x00, 0x01, 0xC0, 0xA8, 0x00, 0xC7,
+        ];
+        let mut writer = ByteOrdered::le(Vec::new()).internet_checksum();
+        writer.write_all(&header[..7]).unwrap();
+        writer.write_all(&header[7..]).unwrap();
+        let checksum = writer.inner_mut().checksum();
+        assert_eq!(checksum, 0xB861);
+
+        // a header with a valid checksum sums up to zero
+        let mut data = header;
+        data[10..12].copy_from_slice(&checksum.to_be_bytes());
+        let mut reader = ByteOrdered::be(&data[..]).internet_checksum();
+        assert_eq!(reader.read_u32().unwrap(), 0x4500_0073);
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(reader.inner_mut().checksum(), 0);
+
+        // odd-length data is padded with zeros
+        let mut writer = InternetChecksum::new(Vec::new());
+        assert_eq!(writer.checksum(), 0xFFFF);
+        writer.write_all(&[0x01, 0x02, 0x03]).unwrap();
+        assert_eq!(writer.checksum(), !0x0402);
+        writer.write_all(&[0x04]).unwrap();
+        assert_eq!(writer.checksum(), !0x0406);
+        assert_eq!(writer.into_inner(), [1, 2, 3, 4]);
+    }
+}
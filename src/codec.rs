@@ -0,0 +1,1903 @@
+//! Decoding and encoding of less common data representations
+//! on top of typed reads and writes.
+
+use byteorder::ReadBytesExt;
+use std::io::{BufRead, Error as IoError, ErrorKind, Read, Result as IoResult, Write};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use {ByteOrdered, Endian};
+
+/// The order of the channels in a pixel packed into a 32 bit integer,
+/// from the most significant byte to the least significant byte,
+/// for use with [`ByteOrdered::read_rgba8888`]
+/// and [`ByteOrdered::write_rgba8888`].
+///
+/// [`ByteOrdered::read_rgba8888`]: struct.ByteOrdered.html#method.read_rgba8888
+/// [`ByteOrdered::write_rgba8888`]: struct.ByteOrdered.html#method.write_rgba8888
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChannelOrder {
+    /// Red, green, blue, then alpha.
+    Rgba,
+    /// Alpha, red, green, then blue.
+    Argb,
+    /// Blue, green, red, then alpha.
+    Bgra,
+}
+
+impl ChannelOrder {
+    /// Obtains the bit shift of the red, green, blue and alpha channels.
+    fn shifts(self) -> [u32; 4] {
+        match self {
+            ChannelOrder::Rgba => [24, 16, 8, 0],
+            ChannelOrder::Argb => [16, 8, 0, 24],
+            ChannelOrder::Bgra => [8, 16, 24, 0],
+        }
+    }
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: ReadBytesExt,
+    E: Endian,
+{
+    /// Reads a single integer from the underlying reader
+    /// and splits it into bit fields.
+    ///
+    /// `fields` contains the width in bits of each field,
+    /// from the most significant to the least significant bits.
+    /// The widths must add up to 8, 16, 32 or 64,
+    /// which determines the size of the integer read
+    /// in the assumed byte order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without reading anything
+    /// if the field widths do not add up to a supported integer size.
+    ///
+    /// # Examples
+    ///
+    /// Read a version number packed as 8/8/16 bits:
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0x03, 0x00, 0x02, 0x01][..]);
+    /// assert_eq!(rdr.read_packed(&[8, 8, 16])?, vec![1, 2, 3]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn read_packed(&mut self, fields: &[u8]) -> IoResult<Vec<u64>> {
+        let total: u32 = fields.iter().map(|&w| u32::from(w)).sum();
+        let raw = match total {
+            8 => u64::from(self.read_u8()?),
+            16 => u64::from(self.read_u16()?),
+            32 => u64::from(self.read_u32()?),
+            64 => self.read_u64()?,
+            _ => {
+                return Err(IoError::new(
+                    ErrorKind::InvalidInput,
+                    "bit field widths must add up to 8, 16, 32 or 64",
+                ))
+            }
+        };
+
+        let mut shift = total;
+        Ok(fields
+            .iter()
+            .map(|&width| {
+                let width = u32::from(width);
+                shift -= width;
+                match width {
+                    0 => 0,
+                    64 => raw,
+                    _ => (raw >> shift) & ((1 << width) - 1),
+                }
+            })
+            .collect())
+    }
+
+    /// Reads a signed integer of the given width in bits
+    /// from the underlying reader,
+    /// sign-extended from bit `bits - 1`.
+    ///
+    /// The value takes up the least number of whole bytes
+    /// which can hold `bits` bits,
+    /// and is read as an unsigned integer of that many bytes
+    /// in the assumed byte order.
+    /// Any bits above the given width are ignored.
+    /// This suits samples of odd widths,
+    /// such as those of 12 bit analog to digital converters.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without reading anything
+    /// if `bits` is not between 1 and 64.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0xFF, 0x0F, 0x00, 0x08][..]);
+    /// assert_eq!(rdr.read_signed_packed(12)?, -1);
+    /// assert_eq!(rdr.read_signed_packed(12)?, -2048);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn read_signed_packed(&mut self, bits: u32) -> IoResult<i64> {
+        if !(1..=64).contains(&bits) {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("unsupported integer width of {} bits", bits),
+            ));
+        }
+        let mut buf = [0; 8];
+        let bytes = &mut buf[..(bits as usize - 1) / 8 + 1];
+        self.read_exact(bytes)?;
+        if !self.is_big_endian() {
+            bytes.reverse();
+        }
+        let raw = bytes.iter().fold(0u64, |acc, &b| acc << 8 | u64::from(b));
+        let unused = 64 - bits;
+        Ok((raw << unused) as i64 >> unused)
+    }
+
+    /// Reads `n` delta-encoded unsigned 32 bit integers
+    /// from the underlying reader.
+    ///
+    /// The first value is taken as is,
+    /// and each following value is added to the previous one,
+    /// so that the returned vector contains the running sums.
+    /// This encoding is common in tables of sorted offsets or timestamps.
+    /// Overflowing sums wrap around.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0, 0, 0, 100, 0, 0, 0, 5, 0, 0, 0, 20][..]);
+    /// assert_eq!(rdr.read_u32_delta(3)?, vec![100, 105, 125]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_u32_delta(&mut self, n: usize) -> IoResult<Vec<u32>> {
+        let mut values = vec![0; n];
+        self.read_u32_into(&mut values)?;
+        for i in 1..n {
+            values[i] = values[i].wrapping_add(values[i - 1]);
+        }
+        Ok(values)
+    }
+
+    /// Reads `n` delta-encoded signed 32 bit integers
+    /// from the underlying reader.
+    ///
+    /// The first value is taken as is,
+    /// and each following value is a signed difference
+    /// from the previous one,
+    /// so that the sequence may go both up and down.
+    /// Overflowing sums wrap around.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_i32_delta(&mut self, n: usize) -> IoResult<Vec<i32>> {
+        let mut values = vec![0; n];
+        self.read_i32_into(&mut values)?;
+        for i in 1..n {
+            values[i] = values[i].wrapping_add(values[i - 1]);
+        }
+        Ok(values)
+    }
+
+    /// Reads an unsigned integer written as `len` ASCII digits
+    /// in the given radix,
+    /// such as the octal size fields of tar headers.
+    ///
+    /// Leading and trailing whitespace and NUL bytes are ignored.
+    /// The byte order does not apply to this method,
+    /// which is only provided so that hybrid binary and text formats
+    /// can be parsed through the same wrapper.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the field is empty, contains anything other than digits
+    /// in the given radix (including a sign), or does not fit in a `u64`.
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without reading anything if `radix` is not in the range `2..=36`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&b"00000001750\0"[..]);
+    /// assert_eq!(rdr.read_ascii_uint(12, 8)?, 0o1750);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn read_ascii_uint(&mut self, len: usize, radix: u32) -> IoResult<u64> {
+        let field = self.read_ascii_field(len, radix)?;
+        // `from_str_radix` would also accept a leading `+`
+        if field.starts_with(|c: char| !c.is_digit(radix)) {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!("invalid digit in unsigned number {:?}", field),
+            ));
+        }
+        u64::from_str_radix(&field, radix).map_err(|e| IoError::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Reads a signed integer written as `len` ASCII digits
+    /// in the given radix,
+    /// optionally preceded by a sign.
+    ///
+    /// Leading and trailing whitespace and NUL bytes are ignored,
+    /// as in [`read_ascii_uint`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the field is empty, contains anything other than a sign
+    /// and digits in the given radix, or does not fit in an `i64`.
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without reading anything if `radix` is not in the range `2..=36`.
+    ///
+    /// [`read_ascii_uint`]: #method.read_ascii_uint
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn read_ascii_int(&mut self, len: usize, radix: u32) -> IoResult<i64> {
+        let field = self.read_ascii_field(len, radix)?;
+        i64::from_str_radix(&field, radix).map_err(|e| IoError::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Reads a fixed-width ASCII field,
+    /// with padding whitespace and NUL bytes removed.
+    fn read_ascii_field(&mut self, len: usize, radix: u32) -> IoResult<String> {
+        if !(2..=36).contains(&radix) {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "radix must be in the range 2..=36",
+            ));
+        }
+        let mut buf = vec![0; len];
+        self.read_exact(&mut buf)?;
+        let is_padding = |b: &u8| *b == 0 || b.is_ascii_whitespace();
+        let start = buf.iter().position(|b| !is_padding(b)).unwrap_or(len);
+        let end = buf
+            .iter()
+            .rposition(|b| !is_padding(b))
+            .map_or(start, |i| i + 1);
+        buf.truncate(end);
+        buf.drain(..start);
+        String::from_utf8(buf).map_err(|e| IoError::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Reads a IEEE754 single-precision (4 bytes) floating point number
+    /// from the underlying reader
+    /// and returns an integer key which sorts in the same order.
+    ///
+    /// The key follows the IEEE 754 _total order_ predicate
+    /// (the same as `f32::total_cmp`):
+    /// the sign bit of positive numbers is set,
+    /// whereas all bits of negative numbers are inverted.
+    /// As a result, negative NaNs come first,
+    /// then negative infinity, negative numbers,
+    /// negative zero, positive zero, positive numbers, positive infinity,
+    /// and finally positive NaNs.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_f32_orderable(&mut self) -> IoResult<u32> {
+        let bits = self.read_f32()?.to_bits();
+        Ok(if bits & (1 << 31) != 0 {
+            !bits
+        } else {
+            bits | (1 << 31)
+        })
+    }
+
+    /// Reads a IEEE754 double-precision (8 bytes) floating point number
+    /// from the underlying reader
+    /// and returns an integer key which sorts in the same order.
+    ///
+    /// The key follows the IEEE 754 _total order_ predicate,
+    /// as described in [`read_f32_orderable`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut wtr = ByteOrdered::le(Vec::new());
+    /// for v in &[2.5, -1.0, 0.0] {
+    ///     wtr.write_f64(*v)?;
+    /// }
+    /// let data = wtr.into_inner();
+    /// let mut rdr = ByteOrdered::le(&data[..]);
+    /// let mut keys = Vec::new();
+    /// for _ in 0..3 {
+    ///     keys.push(rdr.read_f64_orderable()?);
+    /// }
+    /// assert!(keys[1] < keys[2] && keys[2] < keys[0]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`read_f32_orderable`]: #method.read_f32_orderable
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_f64_orderable(&mut self) -> IoResult<u64> {
+        let bits = self.read_f64()?.to_bits();
+        Ok(if bits & (1 << 63) != 0 {
+            !bits
+        } else {
+            bits | (1 << 63)
+        })
+    }
+
+    /// Reads a NUL-terminated UTF-16 string from the underlying reader,
+    /// with each code unit in the assumed byte order.
+    ///
+    /// Code units are read up to and including the terminating `0x0000`,
+    /// which is not part of the returned string.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`],
+    /// including when the stream ends before the terminator.
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the code units are not valid UTF-16,
+    /// such as with unpaired surrogates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&b"O\0K\0\0\0"[..]);
+    /// assert_eq!(rdr.read_utf16_cstring()?, "OK");
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_utf16_cstring(&mut self) -> IoResult<String> {
+        let mut units = Vec::new();
+        loop {
+            match self.read_u16()? {
+                0 => break,
+                unit => units.push(unit),
+            }
+        }
+        String::from_utf16(&units).map_err(|e| IoError::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Reads a character from the underlying reader,
+    /// as a 32 bit Unicode code point in the assumed byte order,
+    /// as in UTF-32.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the code point is not a Unicode scalar value,
+    /// that is, if it is a surrogate or greater than `U+10FFFF`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x00, 0x01, 0xF9, 0x80][..]);
+    /// assert_eq!(rdr.read_char()?, '\u{1F980}');
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_char(&mut self) -> IoResult<char> {
+        let code = self.read_u32()?;
+        ::std::char::from_u32(code).ok_or_else(|| {
+            let reason = if (0xD800..=0xDFFF).contains(&code) {
+                "a surrogate"
+            } else {
+                "out of range"
+            };
+            IoError::new(
+                ErrorKind::InvalidData,
+                format!("invalid code point {:#X}: {}", code, reason),
+            )
+        })
+    }
+
+    /// Reads a NUL-terminated byte string from the underlying reader,
+    /// one byte at a time.
+    ///
+    /// Bytes are read up to and including the terminating NUL,
+    /// which is not part of the returned string.
+    /// This works on any reader,
+    /// but issues one read call per byte.
+    /// When the underlying reader implements [`BufRead`],
+    /// [`read_cstring`] is considerably faster.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`],
+    /// including when the stream ends before the terminator.
+    ///
+    /// [`BufRead`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+    /// [`read_cstring`]: #method.read_cstring
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_cstring_unbuffered(&mut self) -> IoResult<Vec<u8>> {
+        let mut out = Vec::new();
+        loop {
+            match self.read_u8()? {
+                0 => return Ok(out),
+                b => out.push(b),
+            }
+        }
+    }
+
+    /// Reads a Windows `FILETIME` from the underlying reader.
+    ///
+    /// A `FILETIME` is an unsigned 64 bit integer,
+    /// read in the assumed byte order,
+    /// counting intervals of 100 nanoseconds
+    /// since 1601-01-01 00:00:00 UTC.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the time cannot be represented by `SystemTime`
+    /// on the current platform.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::time::UNIX_EPOCH;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let data = [0x00, 0x80, 0x3E, 0xD5, 0xDE, 0xB1, 0x9D, 0x01];
+    /// let mut rdr = ByteOrdered::le(&data[..]);
+    /// assert_eq!(rdr.read_filetime()?, UNIX_EPOCH);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_filetime(&mut self) -> IoResult<SystemTime> {
+        let ticks = self.read_u64()?;
+        let since_1601 = Duration::new(
+            ticks / FILETIME_TICKS_PER_SEC,
+            (ticks % FILETIME_TICKS_PER_SEC) as u32 * 100,
+        );
+        from_epoch_offset(since_1601, FILETIME_EPOCH_OFFSET)
+    }
+
+    /// Reads an NTP timestamp from the underlying reader.
+    ///
+    /// An NTP timestamp is an unsigned 64 bit fixed-point number,
+    /// read in the assumed byte order,
+    /// with the upper 32 bits counting seconds
+    /// since 1900-01-01 00:00:00 UTC
+    /// and the lower 32 bits holding the fraction of a second.
+    /// The fraction is truncated to whole nanoseconds.
+    /// Timestamps are assumed to be in NTP era 0,
+    /// which ends in February 2036.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the time cannot be represented by `SystemTime`
+    /// on the current platform.
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_ntp_timestamp(&mut self) -> IoResult<SystemTime> {
+        let value = self.read_u64()?;
+        let nanos = ((value & 0xFFFF_FFFF) * 1_000_000_000) >> 32;
+        let since_1900 = Duration::new(value >> 32, nanos as u32);
+        from_epoch_offset(since_1900, NTP_EPOCH_OFFSET)
+    }
+
+    /// Reads a signed 32 bit integer in sign-magnitude representation
+    /// from the underlying reader.
+    ///
+    /// In this representation,
+    /// the most significant bit is the sign
+    /// and the remaining 31 bits hold the absolute value.
+    /// Values therefore range from -(2<sup>31</sup> - 1)
+    /// to 2<sup>31</sup> - 1,
+    /// and negative zero (`0x8000_0000`) is read as 0.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x80, 0x00, 0x00, 0x05][..]);
+    /// assert_eq!(rdr.read_i32_sign_magnitude()?, -5);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_i32_sign_magnitude(&mut self) -> IoResult<i32> {
+        let bits = self.read_u32()?;
+        let magnitude = (bits & 0x7FFF_FFFF) as i32;
+        Ok(if bits & 0x8000_0000 != 0 {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+
+    /// Reads a signed 32 bit integer in one's complement representation
+    /// from the underlying reader.
+    ///
+    /// In this representation,
+    /// a negative number is the bitwise complement of its absolute value.
+    /// Values therefore range from -(2<sup>31</sup> - 1)
+    /// to 2<sup>31</sup> - 1,
+    /// and negative zero (`0xFFFF_FFFF`) is read as 0.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_i32_ones_complement(&mut self) -> IoResult<i32> {
+        let bits = self.read_u32()?;
+        Ok(if bits & 0x8000_0000 != 0 {
+            -((!bits) as i32)
+        } else {
+            bits as i32
+        })
+    }
+
+    /// Reads an RGB565 pixel from the underlying reader,
+    /// returning its red, green and blue channels.
+    ///
+    /// The pixel is an unsigned 16 bit integer in the assumed byte order,
+    /// with 5 bits of red in the most significant bits,
+    /// followed by 6 bits of green and 5 bits of blue.
+    /// Each channel is expanded to 8 bits by replicating its upper bits,
+    /// so that the full range is preserved
+    /// (e.g. `0x1F` becomes `0xFF`).
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0x00, 0xF8][..]);
+    /// assert_eq!(rdr.read_rgb565()?, (0xFF, 0x00, 0x00));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_rgb565(&mut self) -> IoResult<(u8, u8, u8)> {
+        let v = self.read_u16()?;
+        let r = (v >> 11) as u8;
+        let g = ((v >> 5) & 0x3F) as u8;
+        let b = (v & 0x1F) as u8;
+        Ok((r << 3 | r >> 2, g << 2 | g >> 4, b << 3 | b >> 2))
+    }
+
+    /// Reads a pixel of four 8 bit channels packed in an unsigned 32 bit integer
+    /// from the underlying reader,
+    /// returning its red, green, blue and alpha channels.
+    ///
+    /// The integer is read in the assumed byte order,
+    /// and the channels are then extracted
+    /// according to the given channel order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, ChannelOrder};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0x44, 0x33, 0x22, 0x11][..]);
+    /// let pixel = rdr.read_rgba8888(ChannelOrder::Argb)?;
+    /// assert_eq!(pixel, (0x22, 0x33, 0x44, 0x11));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_rgba8888(&mut self, order: ChannelOrder) -> IoResult<(u8, u8, u8, u8)> {
+        let v = self.read_u32()?;
+        let [r, g, b, a] = order.shifts();
+        Ok((
+            (v >> r) as u8,
+            (v >> g) as u8,
+            (v >> b) as u8,
+            (v >> a) as u8,
+        ))
+    }
+
+    /// Reads an 80 bit extended precision floating point number
+    /// from the underlying reader,
+    /// converting it to the nearest `f64`.
+    ///
+    /// In big endian, the sign and exponent come first,
+    /// as in the sample rate of an AIFF file.
+    /// In little endian, the layout is that of the x87 FPU in memory.
+    /// The explicit integer bit of the significand is honored,
+    /// so that unnormal numbers are decoded by their value.
+    /// Values beyond the range of `f64` become infinite,
+    /// and values too small for it are rounded
+    /// to a subnormal number or zero.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let data = [0x40, 0x0E, 0xAC, 0x44, 0, 0, 0, 0, 0, 0];
+    /// let mut rdr = ByteOrdered::be(&data[..]);
+    /// assert_eq!(rdr.read_f80_as_f64()?, 44100.);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_f80_as_f64(&mut self) -> IoResult<f64> {
+        let mut bytes = [0; 10];
+        self.read_exact(&mut bytes)?;
+        if !self.is_big_endian() {
+            bytes.reverse();
+        }
+        Ok(f80_to_f64(bytes))
+    }
+
+    /// Reads an unsigned integer of `nbytes` bytes in packed BCD
+    /// (binary-coded decimal) from the underlying reader.
+    ///
+    /// Each byte holds two decimal digits,
+    /// the most significant one in the upper nibble.
+    /// The bytes themselves are in the assumed byte order,
+    /// so the most significant pair of digits comes first in big endian
+    /// and last in little endian.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without reading anything if `nbytes` is greater than 10,
+    /// and an error of kind [`ErrorKind::InvalidData`] is returned
+    /// if a nibble is not a decimal digit,
+    /// or if the number does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x20, 0x24, 0x07, 0x31][..]);
+    /// assert_eq!(rdr.read_bcd(2)?, 2024);
+    /// let mut rdr = ByteOrdered::le(&[0x31, 0x07][..]);
+    /// assert_eq!(rdr.read_bcd(2)?, 731);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_bcd(&mut self, nbytes: usize) -> IoResult<u64> {
+        let mut buf = [0; MAX_BCD_BYTES];
+        let bytes = buf.get_mut(..nbytes).ok_or_else(|| bcd_too_long(nbytes))?;
+        self.read_exact(bytes)?;
+        if !self.is_big_endian() {
+            bytes.reverse();
+        }
+        let mut value = 0u64;
+        for &b in bytes.iter() {
+            for &digit in &[b >> 4, b & 0x0F] {
+                if digit > 9 {
+                    return Err(IoError::new(
+                        ErrorKind::InvalidData,
+                        format!("invalid BCD byte {:#04X}", b),
+                    ));
+                }
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(u64::from(digit)))
+                    .ok_or_else(|| {
+                        IoError::new(ErrorKind::InvalidData, "BCD number does not fit in 64 bits")
+                    })?;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Reads an IPv4 socket address from the underlying reader,
+    /// as a 4-byte address followed by a 2-byte port.
+    ///
+    /// The address octets are always read in the order they are stored,
+    /// most significant first,
+    /// as is the convention for IPv4 addresses in any byte order.
+    /// The port, on the other hand, is read in the assumed byte order.
+    /// Ports are conventionally stored in network order (big endian),
+    /// so formats following this convention
+    /// should be read with a big endian wrapper,
+    /// while a little endian wrapper suits ports stored in host order
+    /// on little endian machines.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::net::{Ipv4Addr, SocketAddrV4};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[192, 168, 0, 1, 0x1F, 0x90][..]);
+    /// assert_eq!(
+    ///     rdr.read_socket_addr_v4()?,
+    ///     SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 8080)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_socket_addr_v4(&mut self) -> IoResult<SocketAddrV4> {
+        let mut octets = [0; 4];
+        self.read_exact(&mut octets)?;
+        let port = self.read_u16()?;
+        Ok(SocketAddrV4::new(Ipv4Addr::from(octets), port))
+    }
+
+    /// Reads an IPv6 socket address from the underlying reader,
+    /// as a 16-byte address followed by a 2-byte port.
+    ///
+    /// As with [`read_socket_addr_v4`],
+    /// the address bytes are read in the order they are stored,
+    /// and the port is read in the assumed byte order,
+    /// which is conventionally big endian (network order).
+    /// The flow information and scope identifier
+    /// of the resulting address are both zero.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`read_socket_addr_v4`]: #method.read_socket_addr_v4
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_socket_addr_v6(&mut self) -> IoResult<SocketAddrV6> {
+        let mut octets = [0; 16];
+        self.read_exact(&mut octets)?;
+        let port = self.read_u16()?;
+        Ok(SocketAddrV6::new(Ipv6Addr::from(octets), port, 0, 0))
+    }
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: BufRead,
+    E: Endian,
+{
+    /// Reads a NUL-terminated byte string from the underlying buffered reader.
+    ///
+    /// Bytes are read up to and including the terminating NUL,
+    /// which is not part of the returned string.
+    /// See [`read_cstring_unbuffered`]
+    /// for readers which do not implement [`BufRead`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`BufRead::read_until`].
+    /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+    /// if the stream ends before the terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&b"name\0\x01\x00"[..]);
+    /// assert_eq!(rdr.read_cstring()?, b"name");
+    /// assert_eq!(rdr.read_u16()?, 1);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`read_cstring_unbuffered`]: #method.read_cstring_unbuffered
+    /// [`BufRead`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+    /// [`BufRead::read_until`]: https://doc.rust-lang.org/std/io/trait.BufRead.html#method.read_until
+    /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+    pub fn read_cstring(&mut self) -> IoResult<Vec<u8>> {
+        let mut out = Vec::new();
+        self.read_until(0, &mut out)?;
+        if out.pop() != Some(0) {
+            return Err(IoError::new(
+                ErrorKind::UnexpectedEof,
+                "stream ended before the string terminator",
+            ));
+        }
+        Ok(out)
+    }
+}
+
+impl<W, E> ByteOrdered<W, E>
+where
+    W: Write,
+    E: Endian,
+{
+    /// Writes a string to the underlying writer
+    /// as NUL-terminated UTF-16,
+    /// with each code unit in the assumed byte order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the string contains a NUL character,
+    /// since it could not be read back in full.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_utf16_cstring(&mut self, s: &str) -> IoResult<()> {
+        if s.contains('\0') {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "string contains a NUL character",
+            ));
+        }
+        for unit in s.encode_utf16() {
+            self.write_u16(unit)?;
+        }
+        self.write_u16(0)
+    }
+
+    /// Writes a character to the underlying writer,
+    /// as a 32 bit Unicode code point in the assumed byte order,
+    /// as in UTF-32.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_char(&mut self, c: char) -> IoResult<()> {
+        self.write_u32(u32::from(c))
+    }
+
+    /// Writes a time as a Windows `FILETIME` to the underlying writer.
+    ///
+    /// See [`read_filetime`] for a description of the format.
+    /// Precision beyond 100 nanoseconds is truncated.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the time is before 1601 or too far into the future
+    /// to be represented.
+    ///
+    /// [`read_filetime`]: #method.read_filetime
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_filetime(&mut self, t: SystemTime) -> IoResult<()> {
+        let since_1601 = to_epoch_offset(t, FILETIME_EPOCH_OFFSET)?;
+        let ticks = since_1601
+            .as_secs()
+            .checked_mul(FILETIME_TICKS_PER_SEC)
+            .and_then(|t| t.checked_add(u64::from(since_1601.subsec_nanos() / 100)))
+            .ok_or_else(|| out_of_range("FILETIME"))?;
+        self.write_u64(ticks)
+    }
+
+    /// Writes a time as an NTP timestamp to the underlying writer.
+    ///
+    /// See [`read_ntp_timestamp`] for a description of the format.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the time is outside of NTP era 0,
+    /// from 1900 until February 2036.
+    ///
+    /// [`read_ntp_timestamp`]: #method.read_ntp_timestamp
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_ntp_timestamp(&mut self, t: SystemTime) -> IoResult<()> {
+        let since_1900 = to_epoch_offset(t, NTP_EPOCH_OFFSET)?;
+        if since_1900.as_secs() > 0xFFFF_FFFF {
+            return Err(out_of_range("NTP era 0"));
+        }
+        let fraction = (u64::from(since_1900.subsec_nanos()) << 32) / 1_000_000_000;
+        self.write_u64(since_1900.as_secs() << 32 | fraction)
+    }
+
+    /// Writes a signed 32 bit integer in sign-magnitude representation
+    /// to the underlying writer.
+    ///
+    /// See [`read_i32_sign_magnitude`] for a description of the format.
+    /// Zero is always written as positive zero.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the value is `i32::MIN`,
+    /// which has no sign-magnitude representation.
+    ///
+    /// [`read_i32_sign_magnitude`]: #method.read_i32_sign_magnitude
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_i32_sign_magnitude(&mut self, x: i32) -> IoResult<()> {
+        let magnitude = x.checked_abs().ok_or_else(|| unrepresentable(x))? as u32;
+        let sign = if x < 0 { 0x8000_0000 } else { 0 };
+        self.write_u32(sign | magnitude)
+    }
+
+    /// Writes a signed 32 bit integer in one's complement representation
+    /// to the underlying writer.
+    ///
+    /// See [`read_i32_ones_complement`] for a description of the format.
+    /// Zero is always written as positive zero.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the value is `i32::MIN`,
+    /// which has no one's complement representation.
+    ///
+    /// [`read_i32_ones_complement`]: #method.read_i32_ones_complement
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_i32_ones_complement(&mut self, x: i32) -> IoResult<()> {
+        let magnitude = x.checked_abs().ok_or_else(|| unrepresentable(x))? as u32;
+        self.write_u32(if x < 0 { !magnitude } else { magnitude })
+    }
+
+    /// Writes an RGB565 pixel to the underlying writer.
+    ///
+    /// See [`read_rgb565`] for a description of the format.
+    /// The lower bits of each channel are dropped.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`read_rgb565`]: #method.read_rgb565
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    pub fn write_rgb565(&mut self, (r, g, b): (u8, u8, u8)) -> IoResult<()> {
+        let v = u16::from(r >> 3) << 11 | u16::from(g >> 2) << 5 | u16::from(b >> 3);
+        self.write_u16(v)
+    }
+
+    /// Writes a pixel of four 8 bit channels
+    /// packed in an unsigned 32 bit integer
+    /// to the underlying writer.
+    ///
+    /// See [`read_rgba8888`] for a description of the format.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`read_rgba8888`]: #method.read_rgba8888
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    pub fn write_rgba8888(
+        &mut self,
+        (r, g, b, a): (u8, u8, u8, u8),
+        order: ChannelOrder,
+    ) -> IoResult<()> {
+        let [rs, gs, bs, as_] = order.shifts();
+        let v = u32::from(r) << rs | u32::from(g) << gs | u32::from(b) << bs | u32::from(a) << as_;
+        self.write_u32(v)
+    }
+
+    /// Writes a floating point number to the underlying writer
+    /// in 80 bit extended precision.
+    ///
+    /// See [`read_f80_as_f64`] for a description of the format.
+    /// Every `f64` is represented exactly,
+    /// including subnormal numbers, infinities and NaN payloads.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`read_f80_as_f64`]: #method.read_f80_as_f64
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    pub fn write_f64_as_f80(&mut self, x: f64) -> IoResult<()> {
+        let mut bytes = f64_to_f80(x);
+        if !self.is_big_endian() {
+            bytes.reverse();
+        }
+        self.write_all(&bytes)
+    }
+
+    /// Writes an unsigned integer to the underlying writer
+    /// in `nbytes` bytes of packed BCD (binary-coded decimal),
+    /// padded with leading zeros.
+    ///
+    /// See [`read_bcd`] for a description of the format.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if `nbytes` is greater than 10,
+    /// or if the value has more than `2 * nbytes` decimal digits.
+    ///
+    /// [`read_bcd`]: #method.read_bcd
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_bcd(&mut self, value: u64, nbytes: usize) -> IoResult<()> {
+        let mut buf = [0; MAX_BCD_BYTES];
+        let bytes = buf.get_mut(..nbytes).ok_or_else(|| bcd_too_long(nbytes))?;
+        let mut rest = value;
+        for b in bytes.iter_mut().rev() {
+            let units = (rest % 10) as u8;
+            let tens = (rest / 10 % 10) as u8;
+            *b = tens << 4 | units;
+            rest /= 100;
+        }
+        if rest != 0 {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("{} does not fit in {} BCD bytes", value, nbytes),
+            ));
+        }
+        if !self.is_big_endian() {
+            bytes.reverse();
+        }
+        self.write_all(bytes)
+    }
+}
+
+/// Creates an error for an integer
+/// which cannot be encoded in the requested representation.
+fn unrepresentable(x: i32) -> IoError {
+    IoError::new(
+        ErrorKind::InvalidInput,
+        format!("{} cannot be represented", x),
+    )
+}
+
+/// The number of `FILETIME` intervals in a second.
+const FILETIME_TICKS_PER_SEC: u64 = 10_000_000;
+
+/// Seconds from 1601-01-01 to the Unix epoch.
+const FILETIME_EPOCH_OFFSET: u64 = 11_644_473_600;
+
+/// Seconds from 1900-01-01 to the Unix epoch.
+const NTP_EPOCH_OFFSET: u64 = 2_208_988_800;
+
+/// Converts a duration since an epoch
+/// which is `offset` seconds before the Unix epoch
+/// into a system time.
+fn from_epoch_offset(since_epoch: Duration, offset: u64) -> IoResult<SystemTime> {
+    let offset = Duration::from_secs(offset);
+    let t = if since_epoch >= offset {
+        UNIX_EPOCH.checked_add(since_epoch - offset)
+    } else {
+        UNIX_EPOCH.checked_sub(offset - since_epoch)
+    };
+    t.ok_or_else(|| {
+        IoError::new(
+            ErrorKind::InvalidData,
+            "time cannot be represented on this platform",
+        )
+    })
+}
+
+/// Converts a system time into a duration since an epoch
+/// which is `offset` seconds before the Unix epoch.
+fn to_epoch_offset(t: SystemTime, offset: u64) -> IoResult<Duration> {
+    let offset = Duration::from_secs(offset);
+    let d = match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.checked_add(offset),
+        Err(e) => offset.checked_sub(e.duration()),
+    };
+    d.ok_or_else(|| out_of_range("the epoch"))
+}
+
+/// Creates an error for a time which cannot be encoded.
+fn out_of_range(what: &str) -> IoError {
+    IoError::new(
+        ErrorKind::InvalidInput,
+        format!("time is out of the range of {}", what),
+    )
+}
+
+/// The maximum length of a packed BCD number,
+/// enough for all 20 digits of `u64::MAX`.
+const MAX_BCD_BYTES: usize = 10;
+
+/// Creates an error for a packed BCD number
+/// which is longer than supported.
+fn bcd_too_long(nbytes: usize) -> IoError {
+    IoError::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "BCD numbers of {} bytes are not supported (at most {})",
+            nbytes, MAX_BCD_BYTES
+        ),
+    )
+}
+
+/// The exponent bias of 80 bit extended precision numbers.
+const F80_BIAS: i32 = 16383;
+
+/// The exponent bias of `f64`.
+const F64_BIAS: i32 = 1023;
+
+/// Converts the big endian bytes of an 80 bit extended precision number
+/// to the nearest `f64`.
+fn f80_to_f64(bytes: [u8; 10]) -> f64 {
+    let se = u16::from(bytes[0]) << 8 | u16::from(bytes[1]);
+    let mut mantissa = 0u64;
+    for &b in &bytes[2..] {
+        mantissa = mantissa << 8 | u64::from(b);
+    }
+    let sign = u64::from(se >> 15) << 63;
+    let exp = i32::from(se & 0x7FFF);
+
+    if exp == 0x7FFF {
+        // the integer bit is not significant here
+        let fraction = mantissa << 1 >> 12;
+        return if mantissa << 1 == 0 {
+            f64::from_bits(sign | 0x7FF0_0000_0000_0000)
+        } else {
+            f64::from_bits(sign | 0x7FF8_0000_0000_0000 | fraction)
+        };
+    }
+    if mantissa == 0 {
+        return f64::from_bits(sign);
+    }
+
+    // normalize, so that the integer bit is set
+    let shift = mantissa.leading_zeros();
+    let mantissa = mantissa << shift;
+    // subnormal numbers share the exponent of the smallest normal numbers
+    let exp = exp.max(1) - F80_BIAS - shift as i32 + F64_BIAS;
+    let bits = if exp >= 0x7FF {
+        0x7FF0_0000_0000_0000
+    } else if exp >= 1 {
+        // the integer bit carries into the exponent
+        (((exp - 1) as u64) << 52) + round_shift(mantissa, 11)
+    } else {
+        round_shift(mantissa, (12 - exp) as u32)
+    };
+    if bits >= 0x7FF0_0000_0000_0000 {
+        f64::from_bits(sign | 0x7FF0_0000_0000_0000)
+    } else {
+        f64::from_bits(sign | bits)
+    }
+}
+
+/// Converts an `f64` to the big endian bytes
+/// of an 80 bit extended precision number.
+fn f64_to_f80(x: f64) -> [u8; 10] {
+    let bits = x.to_bits();
+    let sign = (bits >> 63) as u16;
+    let exp = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & 0x000F_FFFF_FFFF_FFFF;
+
+    let (exp, mantissa) = match (exp, fraction) {
+        (0, 0) => (0, 0),
+        (0, _) => {
+            let shift = fraction.leading_zeros();
+            let exp = 63 - 1074 - shift as i32 + F80_BIAS;
+            (exp as u16, fraction << shift)
+        }
+        (0x7FF, _) => (0x7FFF, 1 << 63 | fraction << 11),
+        _ => ((exp - F64_BIAS + F80_BIAS) as u16, 1 << 63 | fraction << 11),
+    };
+
+    let se = sign << 15 | exp;
+    let mut bytes = [0; 10];
+    bytes[..2].copy_from_slice(&[(se >> 8) as u8, se as u8]);
+    for (i, b) in bytes[2..].iter_mut().enumerate() {
+        *b = (mantissa >> (56 - 8 * i)) as u8;
+    }
+    bytes
+}
+
+/// Shifts `x` right by `n` bits,
+/// rounding to the nearest integer and ties to even.
+fn round_shift(x: u64, n: u32) -> u64 {
+    if n > 65 {
+        return 0;
+    }
+    let x = u128::from(x);
+    let q = x >> n;
+    let rem = x & ((1 << n) - 1);
+    let half = 1 << (n - 1);
+    if rem > half || (rem == half && q & 1 == 1) {
+        (q + 1) as u64
+    } else {
+        q as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChannelOrder;
+    use base::Endianness;
+    use std::io::{ErrorKind, Read, Result as IoResult};
+    use std::time::{Duration, UNIX_EPOCH};
+    use wrap::ByteOrdered;
+
+    #[test]
+    fn test_read_packed() {
+        let data: &[u8] = &[0x01, 0x02, 0x00, 0x03];
+        let mut reader = ByteOrdered::be(data);
+        assert_eq!(reader.read_packed(&[8, 8, 16]).unwrap(), vec![1, 2, 3]);
+
+        let data: &[u8] = &[0x03, 0x00, 0x02, 0x01];
+        let mut reader = ByteOrdered::runtime(data, Endianness::Little);
+        assert_eq!(reader.read_packed(&[8, 8, 16]).unwrap(), vec![1, 2, 3]);
+
+        // odd widths and whole words
+        let data: &[u8] = &[0b1011_0110, 0xFF];
+        let mut reader = ByteOrdered::be(data);
+        assert_eq!(reader.read_packed(&[1, 3, 0, 4]).unwrap(), vec![1, 3, 0, 6]);
+        assert_eq!(reader.read_packed(&[8]).unwrap(), vec![0xFF]);
+
+        let data: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+        let mut reader = ByteOrdered::be(data);
+        assert_eq!(
+            reader.read_packed(&[64]).unwrap(),
+            vec![0x1234_5678_9ABC_DEF0]
+        );
+    }
+
+    #[test]
+    fn test_read_packed_invalid_widths() {
+        let data: &[u8] = &[0x01, 0x02, 0x00, 0x03];
+        let mut reader = ByteOrdered::be(data);
+        let e = reader.read_packed(&[8, 8, 8]).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        let e = reader.read_packed(&[]).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        // nothing was consumed
+        assert_eq!(reader.read_u32().unwrap(), 0x0102_0003);
+    }
+
+    #[test]
+    fn test_read_signed_packed() {
+        let cases: &[(u32, u64, i64)] = &[
+            (12, 0x7FF, 2047),
+            (12, 0x800, -2048),
+            (12, 0xFFF, -1),
+            (12, 0x123, 0x123),
+            (20, 0x7_FFFF, 524_287),
+            (20, 0x8_0000, -524_288),
+            (20, 0xF_FFFE, -2),
+            (1, 1, -1),
+            (8, 0x80, -128),
+            (64, u64::MAX, -1),
+        ];
+        for &e in &[Endianness::Little, Endianness::Big] {
+            for &(bits, raw, expected) in cases {
+                let nbytes = (bits as usize - 1) / 8 + 1;
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_u64(raw).unwrap();
+                let mut data = writer.into_inner();
+                if e == Endianness::Big {
+                    data.drain(..8 - nbytes);
+                } else {
+                    data.truncate(nbytes);
+                }
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                assert_eq!(reader.read_signed_packed(bits).unwrap(), expected);
+                assert!(reader.into_inner().is_empty());
+            }
+            // bits above the width are ignored
+            let mut reader = ByteOrdered::runtime(&[0xF8, 0xF8][..], e);
+            assert_eq!(reader.read_signed_packed(12).unwrap(), -0x708);
+
+            let mut reader = ByteOrdered::runtime(&[0; 9][..], e);
+            for &bits in &[0, 65] {
+                let err = reader.read_signed_packed(bits).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            }
+            assert_eq!(reader.into_inner().len(), 9);
+        }
+    }
+
+    #[test]
+    fn test_read_delta() {
+        // 1000, 1010, 1015, 1115 as deltas
+        let data: &[u8] = &[
+            0x00, 0x00, 0x03, 0xE8, 0x00, 0x00, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+            0x00, 0x64,
+        ];
+        let mut reader = ByteOrdered::be(data);
+        assert_eq!(
+            reader.read_u32_delta(4).unwrap(),
+            vec![1000, 1010, 1015, 1115]
+        );
+
+        // -5, +3, -10 from 20: 20, 15, 18, 8
+        let data: &[u8] = &[
+            0x14, 0x00, 0x00, 0x00, 0xFB, 0xFF, 0xFF, 0xFF, 0x03, 0x00, 0x00, 0x00, 0xF6, 0xFF,
+            0xFF, 0xFF,
+        ];
+        let mut reader = ByteOrdered::runtime(data, Endianness::Little);
+        assert_eq!(reader.read_i32_delta(4).unwrap(), vec![20, 15, 18, 8]);
+
+        // wrapping and empty sequences
+        let data: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF, 0x02, 0x00, 0x00, 0x00];
+        let mut reader = ByteOrdered::le(data);
+        assert_eq!(reader.read_u32_delta(0).unwrap(), vec![]);
+        assert_eq!(reader.read_u32_delta(2).unwrap(), vec![0xFFFF_FFFF, 1]);
+        let e = reader.read_u32_delta(1).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_read_ascii_numbers() {
+        let data: &[u8] = b"0000644\0 1234 \0\0  -42 +7ff";
+        let mut reader = ByteOrdered::le(data);
+        assert_eq!(reader.read_ascii_uint(8, 8).unwrap(), 0o644);
+        assert_eq!(reader.read_ascii_uint(8, 10).unwrap(), 1234);
+        assert_eq!(reader.read_ascii_int(6, 10).unwrap(), -42);
+        assert_eq!(reader.read_ascii_int(4, 16).unwrap(), 0x7FF);
+
+        // digits out of range, inner padding, blank fields
+        let data: &[u8] = b"0009 1 2    +12";
+        let mut reader = ByteOrdered::be(data);
+        let e = reader.read_ascii_uint(4, 8).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        let e = reader.read_ascii_uint(4, 10).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        let e = reader.read_ascii_int(4, 10).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        // unsigned numbers take no sign
+        let e = reader.read_ascii_uint(3, 10).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        let e = reader.read_ascii_uint(0, 1).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_read_float_orderable() {
+        // in ascending total order
+        let values64 = [
+            -f64::NAN,
+            f64::NEG_INFINITY,
+            f64::MIN,
+            -1.0,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE,
+            1.0,
+            f64::MAX,
+            f64::INFINITY,
+            f64::NAN,
+        ];
+        let values32 = [
+            -f32::NAN,
+            f32::NEG_INFINITY,
+            f32::MIN,
+            -1.0,
+            -0.0,
+            0.0,
+            f32::MIN_POSITIVE,
+            f32::MAX,
+            f32::INFINITY,
+            f32::NAN,
+        ];
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            for v in &values64 {
+                writer.write_f64(*v).unwrap();
+            }
+            for v in &values32 {
+                writer.write_f32(*v).unwrap();
+            }
+            let data = writer.into_inner();
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let keys64: Vec<_> = values64
+                .iter()
+                .map(|_| reader.read_f64_orderable().unwrap())
+                .collect();
+            let keys32: Vec<_> = values32
+                .iter()
+                .map(|_| reader.read_f32_orderable().unwrap())
+                .collect();
+            assert!(keys64.windows(2).all(|w| w[0] < w[1]), "{:?}", keys64);
+            assert!(keys32.windows(2).all(|w| w[0] < w[1]), "{:?}", keys32);
+        }
+    }
+
+    #[test]
+    fn test_char() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            for &c in &['a', '\u{E9}', '\u{FFFD}', '\u{10FFFF}'] {
+                writer.write_char(c).unwrap();
+            }
+            writer.write_u32(0xDC00).unwrap();
+            writer.write_u32(0x11_0000).unwrap();
+            let data = writer.into_inner();
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_char().unwrap(), 'a');
+            assert_eq!(reader.read_char().unwrap(), '\u{E9}');
+            assert_eq!(reader.read_char().unwrap(), '\u{FFFD}');
+            assert_eq!(reader.read_char().unwrap(), '\u{10FFFF}');
+            let err = reader.read_char().unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+            assert_eq!(err.to_string(), "invalid code point 0xDC00: a surrogate");
+            let err = reader.read_char().unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+            assert_eq!(err.to_string(), "invalid code point 0x110000: out of range");
+        }
+        let mut reader = ByteOrdered::le(&[0x41, 0, 0, 0][..]);
+        assert_eq!(reader.read_char().unwrap(), 'A');
+    }
+
+    #[test]
+    fn test_utf16_cstring() {
+        let text = "Grüße";
+        let le: &[u8] = &[b'G', 0, b'r', 0, 0xFC, 0, 0xDF, 0, b'e', 0, 0, 0, 0xFF];
+        let be: &[u8] = &[0, b'G', 0, b'r', 0, 0xFC, 0, 0xDF, 0, b'e', 0, 0, 0xFF];
+        for &(e, data) in &[(Endianness::Little, le), (Endianness::Big, be)] {
+            let mut reader = ByteOrdered::runtime(data, e);
+            assert_eq!(reader.read_utf16_cstring().unwrap(), text);
+            // stops right after the terminator
+            assert_eq!(reader.into_inner(), &[0xFF]);
+
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_utf16_cstring(text).unwrap();
+            assert_eq!(writer.into_inner(), &data[..data.len() - 1]);
+        }
+
+        // unpaired surrogate
+        let mut reader = ByteOrdered::le(&[0x00, 0xD8, 0x41, 0x00, 0x00, 0x00][..]);
+        let e = reader.read_utf16_cstring().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+
+        // missing terminator
+        let mut reader = ByteOrdered::le(&[0x41, 0x00][..]);
+        let e = reader.read_utf16_cstring().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+
+        let mut writer = ByteOrdered::le(Vec::new());
+        let e = writer.write_utf16_cstring("a\0b").unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        assert!(writer.into_inner().is_empty());
+    }
+
+    #[test]
+    fn test_read_cstring() {
+        /// A reader which does not implement `BufRead`.
+        struct Unbuffered<'a>(&'a [u8]);
+
+        impl<'a> Read for Unbuffered<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+                self.0.read(buf)
+            }
+        }
+
+        let data: &[u8] = b"abc\0\0xy";
+        let mut reader = ByteOrdered::be(Unbuffered(data));
+        assert_eq!(reader.read_cstring_unbuffered().unwrap(), b"abc");
+        assert_eq!(reader.read_cstring_unbuffered().unwrap(), b"");
+        let e = reader.read_cstring_unbuffered().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+
+        let mut reader = ByteOrdered::be(data);
+        assert_eq!(reader.read_cstring().unwrap(), b"abc");
+        assert_eq!(reader.read_cstring().unwrap(), b"");
+        let e = reader.read_cstring().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_filetime() {
+        // 2000-01-01 00:00:00.5 UTC
+        let t = UNIX_EPOCH + Duration::new(946_684_800, 500_000_000);
+        let ticks: u64 = 125_911_584_005_000_000;
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_u64(ticks).unwrap();
+            let data = writer.into_inner();
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_filetime().unwrap(), t);
+
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_filetime(t).unwrap();
+            assert_eq!(writer.into_inner(), data);
+        }
+
+        // the FILETIME epoch itself
+        let mut reader = ByteOrdered::le(&[0u8; 8][..]);
+        let t = reader.read_filetime().unwrap();
+        assert_eq!(
+            UNIX_EPOCH.duration_since(t).unwrap(),
+            Duration::from_secs(11_644_473_600)
+        );
+
+        let mut writer = ByteOrdered::le(Vec::new());
+        let e = writer
+            .write_filetime(t - Duration::from_secs(1))
+            .unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        assert!(writer.into_inner().is_empty());
+    }
+
+    #[test]
+    fn test_ntp_timestamp() {
+        // 2000-01-01 00:00:00.5 UTC
+        let t = UNIX_EPOCH + Duration::new(946_684_800, 500_000_000);
+        let data: &[u8] = &[0xBC, 0x17, 0xC2, 0x00, 0x80, 0x00, 0x00, 0x00];
+        let mut reader = ByteOrdered::be(data);
+        assert_eq!(reader.read_ntp_timestamp().unwrap(), t);
+        let mut writer = ByteOrdered::be(Vec::new());
+        writer.write_ntp_timestamp(t).unwrap();
+        assert_eq!(writer.into_inner(), data);
+
+        let le: Vec<u8> = data.iter().rev().cloned().collect();
+        let mut reader = ByteOrdered::le(&le[..]);
+        assert_eq!(reader.read_ntp_timestamp().unwrap(), t);
+
+        // beyond era 0
+        let mut writer = ByteOrdered::be(Vec::new());
+        let t = UNIX_EPOCH + Duration::from_secs(0x1_0000_0000);
+        let e = writer.write_ntp_timestamp(t).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_sign_magnitude_and_ones_complement() {
+        // (value, sign-magnitude bits, one's complement bits)
+        let cases: &[(i32, u32, u32)] = &[
+            (0, 0x0000_0000, 0x0000_0000),
+            (1, 0x0000_0001, 0x0000_0001),
+            (-1, 0x8000_0001, 0xFFFF_FFFE),
+            (0x7FFF_FFFF, 0x7FFF_FFFF, 0x7FFF_FFFF),
+            (-0x7FFF_FFFF, 0xFFFF_FFFF, 0x8000_0000),
+        ];
+        for &e in &[Endianness::Little, Endianness::Big] {
+            for &(value, sm, oc) in cases {
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_u32(sm).unwrap();
+                writer.write_u32(oc).unwrap();
+                let data = writer.into_inner();
+
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                assert_eq!(reader.read_i32_sign_magnitude().unwrap(), value);
+                assert_eq!(reader.read_i32_ones_complement().unwrap(), value);
+
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_i32_sign_magnitude(value).unwrap();
+                writer.write_i32_ones_complement(value).unwrap();
+                assert_eq!(writer.into_inner(), data);
+            }
+
+            // negative zero
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_u32(0x8000_0000).unwrap();
+            writer.write_u32(0xFFFF_FFFF).unwrap();
+            let data = writer.into_inner();
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_i32_sign_magnitude().unwrap(), 0);
+            assert_eq!(reader.read_i32_ones_complement().unwrap(), 0);
+
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            let err = writer.write_i32_sign_magnitude(i32::MIN).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            let err = writer.write_i32_ones_complement(i32::MIN).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            assert!(writer.into_inner().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_rgb565() {
+        let cases: &[(u16, (u8, u8, u8))] = &[
+            (0x0000, (0x00, 0x00, 0x00)),
+            (0xFFFF, (0xFF, 0xFF, 0xFF)),
+            (0xF800, (0xFF, 0x00, 0x00)),
+            (0x07E0, (0x00, 0xFF, 0x00)),
+            (0x001F, (0x00, 0x00, 0xFF)),
+            (0x8410, (0x84, 0x82, 0x84)),
+        ];
+        for &e in &[Endianness::Little, Endianness::Big] {
+            for &(packed, rgb) in cases {
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_u16(packed).unwrap();
+                let data = writer.into_inner();
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                assert_eq!(reader.read_rgb565().unwrap(), rgb);
+
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_rgb565(rgb).unwrap();
+                assert_eq!(writer.into_inner(), data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rgba8888() {
+        let pixel = (0x11, 0x22, 0x33, 0x44);
+        let cases = [
+            (ChannelOrder::Rgba, 0x1122_3344),
+            (ChannelOrder::Argb, 0x4411_2233),
+            (ChannelOrder::Bgra, 0x3322_1144),
+        ];
+        for &e in &[Endianness::Little, Endianness::Big] {
+            for &(order, packed) in &cases {
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_u32(packed).unwrap();
+                let data = writer.into_inner();
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                assert_eq!(reader.read_rgba8888(order).unwrap(), pixel);
+
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_rgba8888(pixel, order).unwrap();
+                assert_eq!(writer.into_inner(), data);
+            }
+        }
+        // the same bytes in memory are RGBA in big endian, ABGR in little endian
+        let data: &[u8] = &[0x11, 0x22, 0x33, 0x44];
+        let mut reader = ByteOrdered::be(data);
+        assert_eq!(reader.read_rgba8888(ChannelOrder::Rgba).unwrap(), pixel);
+        let mut reader = ByteOrdered::le(data);
+        assert_eq!(
+            reader.read_rgba8888(ChannelOrder::Rgba).unwrap(),
+            (0x44, 0x33, 0x22, 0x11)
+        );
+    }
+
+    #[test]
+    fn test_f80() {
+        // 44100 Hz, as found in AIFF files
+        let aiff = [0x40, 0x0E, 0xAC, 0x44, 0, 0, 0, 0, 0, 0];
+        let mut reader = ByteOrdered::be(&aiff[..]);
+        assert_eq!(reader.read_f80_as_f64().unwrap(), 44100.);
+        let mut swapped = aiff;
+        swapped.reverse();
+        let mut reader = ByteOrdered::le(&swapped[..]);
+        assert_eq!(reader.read_f80_as_f64().unwrap(), 44100.);
+        let mut writer = ByteOrdered::be(Vec::new());
+        writer.write_f64_as_f80(44100.).unwrap();
+        assert_eq!(writer.into_inner(), aiff);
+
+        let values = [
+            0.,
+            -0.,
+            1.,
+            -2.5,
+            ::std::f64::consts::PI,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            -1.5e-310,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+        for &e in &[Endianness::Little, Endianness::Big] {
+            for &x in &values {
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_f64_as_f80(x).unwrap();
+                let data = writer.into_inner();
+                assert_eq!(data.len(), 10);
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                let y = reader.read_f80_as_f64().unwrap();
+                assert_eq!(x.to_bits(), y.to_bits());
+            }
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_f64_as_f80(f64::NAN).unwrap();
+            let data = writer.into_inner();
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert!(reader.read_f80_as_f64().unwrap().is_nan());
+        }
+
+        let cases: &[([u8; 10], f64)] = &[
+            // unnormal: the integer bit is clear
+            ([0x3F, 0xFF, 0x40, 0, 0, 0, 0, 0, 0, 0], 0.5),
+            // ties are rounded to even
+            ([0x3F, 0xFF, 0x80, 0, 0, 0, 0, 0, 0x04, 0], 1.),
+            (
+                [0x3F, 0xFF, 0x80, 0, 0, 0, 0, 0, 0x0C, 0],
+                1. + 2f64.powi(-51),
+            ),
+            // beyond the range of f64
+            ([0x7F, 0xFE, 0x80, 0, 0, 0, 0, 0, 0, 0], f64::INFINITY),
+            ([0xC4, 0x00, 0x80, 0, 0, 0, 0, 0, 0, 0], f64::NEG_INFINITY),
+            // subnormal in f80, too small for f64
+            ([0x00, 0x00, 0x00, 0, 0, 0, 0, 0, 0, 1], 0.),
+            // the smallest subnormal f64
+            ([0x3B, 0xCD, 0x80, 0, 0, 0, 0, 0, 0, 0], 5e-324),
+            // rounded up to the smallest normal f64
+            (
+                [0x3C, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+                f64::MIN_POSITIVE,
+            ),
+        ];
+        for &(bytes, expected) in cases {
+            let mut reader = ByteOrdered::be(&bytes[..]);
+            let x = reader.read_f80_as_f64().unwrap();
+            assert_eq!(x.to_bits(), expected.to_bits(), "{:02X?}", bytes);
+        }
+    }
+
+    #[test]
+    fn test_socket_addr() {
+        use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+        // a peer in "compact" form, as found in BitTorrent tracker responses:
+        // the address octets followed by the port in network order
+        let data: &[u8] = &[0x7F, 0x00, 0x00, 0x01, 0x1F, 0x90];
+        let mut reader = ByteOrdered::be(data);
+        let addr = reader.read_socket_addr_v4().unwrap();
+        assert_eq!(addr, SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080));
+        assert!(reader.into_inner().is_empty());
+
+        // the port honors the wrapper's byte order, the address does not
+        let mut reader = ByteOrdered::le(data);
+        let addr = reader.read_socket_addr_v4().unwrap();
+        assert_eq!(addr, SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0x901F));
+
+        let mut data = Ipv6Addr::LOCALHOST.octets().to_vec();
+        data.extend_from_slice(&[0x01, 0xBB]);
+        let mut reader = ByteOrdered::be(&data[..]);
+        assert_eq!(
+            reader.read_socket_addr_v6().unwrap(),
+            SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0)
+        );
+        let mut reader = ByteOrdered::le(&data[..]);
+        assert_eq!(reader.read_socket_addr_v6().unwrap().port(), 0xBB01);
+
+        let mut reader = ByteOrdered::be(&data[..17]);
+        let err = reader.read_socket_addr_v6().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_bcd() {
+        // a date and time as in some telecom records: 2024-07-31 23:59
+        let be = [0x20, 0x24, 0x07, 0x31, 0x23, 0x59];
+        let mut reader = ByteOrdered::be(&be[..]);
+        assert_eq!(reader.read_bcd(2).unwrap(), 2024);
+        assert_eq!(reader.read_bcd(1).unwrap(), 7);
+        assert_eq!(reader.read_bcd(1).unwrap(), 31);
+        assert_eq!(reader.read_bcd(2).unwrap(), 2359);
+        let mut reader = ByteOrdered::le(&[0x59, 0x23][..]);
+        assert_eq!(reader.read_bcd(2).unwrap(), 2359);
+
+        for &e in &[Endianness::Little, Endianness::Big] {
+            for &(value, nbytes) in &[(0, 0), (0, 3), (42, 1), (123_456, 4), (u64::MAX, 10)] {
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_bcd(value, nbytes).unwrap();
+                let data = writer.into_inner();
+                assert_eq!(data.len(), nbytes);
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                assert_eq!(reader.read_bcd(nbytes).unwrap(), value);
+            }
+
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            let err = writer.write_bcd(100, 1).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            let err = writer.write_bcd(1, 11).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            assert!(writer.into_inner().is_empty());
+
+            // invalid nibbles
+            for data in &[[0x1A, 0x00], [0x00, 0xF1]] {
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                let err = reader.read_bcd(2).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::InvalidData);
+            }
+            // beyond u64::MAX
+            let data = [0x99; 10];
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let err = reader.read_bcd(10).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let err = reader.read_bcd(11).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Reading of length-prefixed blocks compressed with deflate,
+//! through the `flate2` crate.
+
+use flate2::bufread::DeflateDecoder;
+use std::convert::TryFrom;
+use std::io::{BufRead, Error as IoError, ErrorKind, Read, Result as IoResult};
+use {ByteOrdered, Endian};
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: BufRead,
+    E: Endian,
+{
+    /// Reads a block consisting of its uncompressed length
+    /// as an unsigned 32 bit integer,
+    /// followed by a raw deflate stream,
+    /// and returns the decompressed data.
+    ///
+    /// The reader must be buffered,
+    /// so that no data past the end of the compressed stream is consumed.
+    /// See [`into_buffered_reader`] to obtain a buffered reader.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`]
+    /// when reading the length,
+    /// and the errors of the decoder when decompressing the data.
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the decompressed data is not of the declared length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate byteordered;
+    /// # extern crate flate2;
+    /// use byteordered::ByteOrdered;
+    /// use flate2::write::DeflateEncoder;
+    /// use flate2::Compression;
+    /// use std::io::Write;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    /// encoder.write_all(b"hello, hello, hello")?;
+    /// let mut wtr = ByteOrdered::le(Vec::new());
+    /// wtr.write_u32(19)?;
+    /// wtr.write_all(&encoder.finish()?)?;
+    /// let data = wtr.into_inner();
+    ///
+    /// let mut rdr = ByteOrdered::le(&data[..]);
+    /// assert_eq!(rdr.read_deflate_block()?, b"hello, hello, hello");
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #   run().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`into_buffered_reader`]: struct.ByteOrdered.html#method.into_buffered_reader
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_deflate_block(&mut self) -> IoResult<Vec<u8>> {
+        let len = self.read_u32()?;
+        let expected = usize::try_from(len).map_err(|_| {
+            IoError::new(
+                ErrorKind::InvalidData,
+                format!("length {} does not fit in the target's address space", len),
+            )
+        })?;
+        let mut data = Vec::new();
+        // one extra byte is requested to detect overlong data
+        DeflateDecoder::new(self.inner_mut())
+            .take(u64::from(len) + 1)
+            .read_to_end(&mut data)?;
+        if data.len() > expected {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!("decompressed data exceeds the declared {} bytes", expected),
+            ));
+        }
+        if data.len() < expected {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "expected {} bytes of decompressed data, found {}",
+                    expected,
+                    data.len()
+                ),
+            ));
+        }
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::Endianness;
+    use flate2::write::DeflateEncoder;
+    use flate2::Compression;
+    use std::io::{ErrorKind, Write};
+    use wrap::ByteOrdered;
+
+    fn block(e: Endianness, len: u32, payload: &[u8]) -> Vec<u8> {
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(payload).unwrap();
+        let mut writer = ByteOrdered::runtime(Vec::new(), e);
+        writer.write_u32(len).unwrap();
+        writer.write_all(&encoder.finish().unwrap()).unwrap();
+        writer.into_inner()
+    }
+
+    #[test]
+    fn test_read_deflate_block() {
+        let payload: Vec<u8> = (0..500u32).map(|i| (i % 7) as u8).collect();
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut data = block(e, 500, &payload);
+            data.extend_from_slice(&block(e, 3, b"abc"));
+            data.push(0x2A);
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_deflate_block().unwrap(), payload);
+            assert_eq!(reader.read_deflate_block().unwrap(), b"abc");
+            // nothing past the blocks was consumed
+            assert_eq!(reader.read_u8().unwrap(), 0x2A);
+
+            for &len in &[499, 501] {
+                let data = block(e, len, &payload);
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                let err = reader.read_deflate_block().unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::InvalidData);
+            }
+        }
+    }
+}
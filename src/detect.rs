@@ -0,0 +1,533 @@
+//! Heuristics and handshakes for determining the byte order
+//! of data without a magic number.
+
+use std::fs::File;
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write};
+use std::path::Path;
+use {ByteOrdered, Endian, Endianness, LenWidth};
+
+/// A byte order obtained from one of the detection heuristics.
+///
+/// This distinguishes a guessed byte order from one which is known,
+/// while remaining cheap to keep around:
+/// applications which reopen the same source
+/// can retain it and skip detection on subsequent opens,
+/// by passing it to [`ByteOrdered::reopen_with`]
+/// or by passing [`cache_key`] to [`ByteOrdered::runtime`].
+///
+/// [`ByteOrdered::reopen_with`]: struct.ByteOrdered.html#method.reopen_with
+/// [`ByteOrdered::runtime`]: struct.ByteOrdered.html#method.runtime
+/// [`cache_key`]: #method.cache_key
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DetectedEndianness(Endianness);
+
+impl DetectedEndianness {
+    /// Retrieves the detected byte order,
+    /// which can be stored and later turned back
+    /// into a `DetectedEndianness` via `From`.
+    #[inline]
+    pub fn cache_key(&self) -> Endianness {
+        self.0
+    }
+}
+
+impl From<Endianness> for DetectedEndianness {
+    #[inline]
+    fn from(e: Endianness) -> Self {
+        DetectedEndianness(e)
+    }
+}
+
+impl From<DetectedEndianness> for Endianness {
+    #[inline]
+    fn from(e: DetectedEndianness) -> Self {
+        e.0
+    }
+}
+
+impl PartialEq<Endianness> for DetectedEndianness {
+    #[inline]
+    fn eq(&self, e: &Endianness) -> bool {
+        self.0 == *e
+    }
+}
+
+impl ByteOrdered<File, Endianness> {
+    /// Opens the file at the given path in read-only mode,
+    /// assuming the byte order detected previously.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`File::open`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use byteordered::{score_endianness, ByteOrdered, LenWidth};
+    /// use std::fs;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let detected = score_endianness(&fs::read("samples.bin")?, LenWidth::U16);
+    /// // ... later on
+    /// let mut rdr = ByteOrdered::reopen_with("samples.bin", detected)?;
+    /// let first = rdr.read_u16()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`File::open`]: https://doc.rust-lang.org/std/fs/struct.File.html#method.open
+    pub fn reopen_with<P>(path: P, detected: DetectedEndianness) -> IoResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path)?;
+        Ok(ByteOrdered::runtime(file, detected.cache_key()))
+    }
+}
+
+impl<R> ByteOrdered<R, Endianness>
+where
+    R: Read,
+{
+    /// Reads a UTF-16 byte order mark (`U+FEFF`) from the given reader,
+    /// such as one written by [`write_order_tag`],
+    /// and wraps the reader in the byte order which it indicates.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the two bytes read are not a byte order mark.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, Endianness};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let data = [0xFF, 0xFE, 0x2A, 0x00];
+    /// let mut rdr = ByteOrdered::detect_u16_bom(&data[..])?;
+    /// assert_eq!(rdr.endianness(), Endianness::Little);
+    /// assert_eq!(rdr.read_u16()?, 42);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`write_order_tag`]: struct.ByteOrdered.html#method.write_order_tag
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn detect_u16_bom(mut inner: R) -> IoResult<Self> {
+        let mut bom = [0; 2];
+        inner.read_exact(&mut bom)?;
+        let e = Endianness::from_u16_bom(bom).ok_or_else(|| {
+            IoError::new(
+                ErrorKind::InvalidData,
+                format!("{:02X?} is not a byte order mark", bom),
+            )
+        })?;
+        Ok(ByteOrdered::runtime(inner, e))
+    }
+}
+
+impl<S> ByteOrdered<S, Endianness>
+where
+    S: Read + Write,
+{
+    /// Negotiates the byte order of a bidirectional stream
+    /// by exchanging a probe value with the peer.
+    ///
+    /// The probe is written to the stream in the native byte order
+    /// and the stream is flushed.
+    /// The peer is then expected to reply with the same probe value,
+    /// written in _its_ native byte order.
+    /// If the reply matches the probe byte for byte,
+    /// both ends share the same byte order;
+    /// if it matches the probe with its bytes swapped,
+    /// the peer uses the opposite byte order.
+    /// The returned wrapper assumes the peer's byte order.
+    ///
+    /// The probe must be different from itself with its bytes swapped
+    /// (such as `0x0102_0304`),
+    /// otherwise the two outcomes could not be told apart.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as
+    /// [`Write::write_all`], [`Write::flush`] and [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything if the probe is a byte palindrome,
+    /// and an error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the reply is not the probe in either byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use byteordered::ByteOrdered;
+    /// use std::net::TcpStream;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let stream = TcpStream::connect("127.0.0.1:7070")?;
+    /// let mut stream = ByteOrdered::negotiate(stream, 0x0102_0304)?;
+    /// let message_len = stream.read_u32()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`Write::flush`]: https://doc.rust-lang.org/std/io/trait.Write.html#tymethod.flush
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn negotiate(stream: S, probe: u32) -> IoResult<Self> {
+        if probe == probe.swap_bytes() {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("probe {:#010X} is the same in both byte orders", probe),
+            ));
+        }
+        let mut stream = ByteOrdered::runtime(stream, Endianness::native());
+        stream.write_u32(probe)?;
+        stream.flush()?;
+        let reply = stream.read_u32()?;
+        if reply == probe {
+            Ok(stream)
+        } else if reply == probe.swap_bytes() {
+            Ok(stream.into_opposite())
+        } else {
+            Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "peer replied with {:#010X} instead of probe {:#010X}",
+                    reply, probe
+                ),
+            ))
+        }
+    }
+}
+
+impl<'a> ByteOrdered<&'a [u8], Endianness> {
+    /// Attempts to decode the given bytes in both byte orders,
+    /// returning the first outcome which is deemed plausible.
+    ///
+    /// `decode` is first called with a reader over `src` in little endian,
+    /// and then, if unsuccessful, with a fresh reader over `src`
+    /// in big endian.
+    /// An attempt is successful if `decode` returns `Ok`
+    /// and `plausible` accepts the decoded value.
+    ///
+    /// This is a heuristic:
+    /// it is only as reliable as the plausibility predicate,
+    /// and data which makes sense in both byte orders
+    /// will always be reported as little endian.
+    /// Prefer a magic number or byte order mark
+    /// whenever the format provides one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, Endianness};
+    ///
+    /// // a header with a small record count
+    /// let data = [0x00, 0x00, 0x00, 0x03];
+    /// let outcome = ByteOrdered::try_both(&data, |rd| rd.read_u32(), |&n| n < 1024);
+    /// assert_eq!(outcome, Some((Endianness::Big.into(), 3)));
+    /// ```
+    pub fn try_both<T, F, P>(
+        src: &'a [u8],
+        mut decode: F,
+        mut plausible: P,
+    ) -> Option<(DetectedEndianness, T)>
+    where
+        F: FnMut(&mut ByteOrdered<&'a [u8], Endianness>) -> IoResult<T>,
+        P: FnMut(&T) -> bool,
+    {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut reader = ByteOrdered::runtime(src, e);
+            match decode(&mut reader) {
+                Ok(value) if plausible(&value) => return Some((e.into(), value)),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Guesses the byte order of a sample of unsigned integers
+/// of the given width,
+/// by picking the order in which the decoded values vary the least.
+///
+/// This suits unlabeled dumps of measurements,
+/// where consecutive values tend to be close to each other:
+/// decoding them in the wrong byte order
+/// moves the noisy low order bytes to the most significant positions,
+/// which greatly increases the variance of the values.
+/// See [`score_endianness_with`] to use a different scoring function.
+///
+/// This is a heuristic with no guarantees.
+/// Ties are resolved in favor of little endian.
+/// Trailing bytes which do not make a full value are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{score_endianness, Endianness, LenWidth};
+///
+/// // slowly increasing 16-bit readings in big endian
+/// let sample = [0x01, 0x10, 0x01, 0x12, 0x01, 0x11, 0x01, 0x15];
+/// assert_eq!(score_endianness(&sample, LenWidth::U16), Endianness::Big);
+/// ```
+///
+/// [`score_endianness_with`]: fn.score_endianness_with.html
+pub fn score_endianness(sample: &[u8], width: LenWidth) -> DetectedEndianness {
+    score_endianness_with(sample, width, variance)
+}
+
+/// Guesses the byte order of a sample of unsigned integers
+/// of the given width,
+/// by picking the order for which the given function
+/// produces the lowest score.
+///
+/// The scoring function receives all values of the sample,
+/// decoded in one of the byte orders.
+/// As in [`score_endianness`], this is a heuristic with no guarantees,
+/// and ties are resolved in favor of little endian.
+///
+/// # Examples
+///
+/// Score by the largest value, so that outliers are penalized:
+///
+/// ```rust
+/// use byteordered::{score_endianness_with, Endianness, LenWidth};
+///
+/// let sample = [0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x09];
+/// let e = score_endianness_with(&sample, LenWidth::U32, |values| {
+///     values.iter().cloned().fold(0, u64::max) as f64
+/// });
+/// assert_eq!(e, Endianness::Big);
+/// ```
+///
+/// [`score_endianness`]: fn.score_endianness.html
+pub fn score_endianness_with<F>(sample: &[u8], width: LenWidth, mut score: F) -> DetectedEndianness
+where
+    F: FnMut(&[u64]) -> f64,
+{
+    let le = score(&decode_all(sample, width, Endianness::Little));
+    let be = score(&decode_all(sample, width, Endianness::Big));
+    DetectedEndianness(if be < le {
+        Endianness::Big
+    } else {
+        Endianness::Little
+    })
+}
+
+/// Decodes all complete values of the given width in the sample.
+fn decode_all(sample: &[u8], width: LenWidth, e: Endianness) -> Vec<u64> {
+    let size = match width {
+        LenWidth::U8 => 1,
+        LenWidth::U16 => 2,
+        LenWidth::U32 => 4,
+        LenWidth::U64 => 8,
+    };
+    sample
+        .chunks_exact(size)
+        .map(|mut chunk| {
+            let value = match width {
+                LenWidth::U8 => Ok(u64::from(chunk[0])),
+                LenWidth::U16 => e.read_u16(&mut chunk).map(u64::from),
+                LenWidth::U32 => e.read_u32(&mut chunk).map(u64::from),
+                LenWidth::U64 => e.read_u64(&mut chunk),
+            };
+            value.expect("reading from a full chunk should not fail")
+        })
+        .collect()
+}
+
+/// Calculates the variance of the given values.
+fn variance(values: &[u64]) -> f64 {
+    if values.is_empty() {
+        return 0.;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().map(|&v| v as f64).sum::<f64>() / n;
+    values
+        .iter()
+        .map(|&v| {
+            let d = v as f64 - mean;
+            d * d
+        })
+        .sum::<f64>()
+        / n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::score_endianness;
+    use base::Endianness;
+    use std::fs;
+    use std::io::{ErrorKind, Read, Result as IoResult, Write};
+    use wrap::{ByteOrdered, LenWidth};
+
+    /// One end of a simulated connection,
+    /// which replies to a probe in its own byte order
+    /// and then sends a 16 bit payload.
+    #[derive(Debug)]
+    struct Peer {
+        order: Endianness,
+        received: Vec<u8>,
+        reply: Vec<u8>,
+    }
+
+    impl Peer {
+        fn new(order: Endianness) -> Self {
+            Peer {
+                order,
+                received: Vec::new(),
+                reply: Vec::new(),
+            }
+        }
+    }
+
+    impl Write for Peer {
+        fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+            self.received.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            if !self.reply.is_empty() {
+                // a reply was already set up by the test
+                return Ok(());
+            }
+            let probe = ByteOrdered::native(&self.received[..]).read_u32()?;
+            let mut writer = ByteOrdered::runtime(Vec::new(), self.order);
+            writer.write_u32(probe)?;
+            writer.write_u16(0xBEEF)?;
+            self.reply = writer.into_inner();
+            Ok(())
+        }
+    }
+
+    impl Read for Peer {
+        fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+            let n = (&self.reply[..]).read(buf)?;
+            self.reply.drain(..n);
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_try_both() {
+        // a length-prefixed string, valid only if the length fits the data
+        let decode = |rd: &mut ByteOrdered<&[u8], Endianness>| {
+            let len = rd.read_u16()? as usize;
+            let mut text = vec![0; len];
+            rd.read_exact(&mut text)?;
+            Ok(text)
+        };
+        let plausible = |text: &Vec<u8>| text.iter().all(u8::is_ascii_alphanumeric);
+
+        let data: &[u8] = &[0x00, 0x03, b'a', b'b', b'c'];
+        assert_eq!(
+            ByteOrdered::try_both(data, decode, plausible),
+            Some((Endianness::Big.into(), b"abc".to_vec()))
+        );
+
+        let data: &[u8] = &[0x02, 0x00, b'x', b'y', 0xFF];
+        assert_eq!(
+            ByteOrdered::try_both(data, decode, plausible),
+            Some((Endianness::Little.into(), b"xy".to_vec()))
+        );
+
+        // the decoded value is rejected in both byte orders
+        let data: &[u8] = &[0x01, 0x01, b'?'];
+        assert_eq!(ByteOrdered::try_both(data, decode, plausible), None);
+    }
+
+    #[test]
+    fn test_score_endianness() {
+        // a slowly varying signal around 1000, with some noise
+        let values: Vec<u32> = (0..64).map(|i| 1000 + (i * 37 % 11)).collect();
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            for &v in &values {
+                writer.write_u32(v).unwrap();
+            }
+            let sample = writer.into_inner();
+            assert_eq!(score_endianness(&sample, LenWidth::U32), e);
+        }
+        assert_eq!(score_endianness(&[], LenWidth::U64), Endianness::Little);
+    }
+
+    #[test]
+    fn test_detect_then_reopen() {
+        let mut writer = ByteOrdered::be(Vec::new());
+        for v in 500..532 {
+            writer.write_u16(v).unwrap();
+        }
+        let data = writer.into_inner();
+        let path = ::std::env::temp_dir().join(format!(
+            "byteordered-test-reopen-{}.bin",
+            ::std::process::id()
+        ));
+        fs::write(&path, &data).unwrap();
+
+        let detected = score_endianness(&fs::read(&path).unwrap(), LenWidth::U16);
+        assert_eq!(detected.cache_key(), Endianness::Big);
+        // as if restored from a cache
+        let detected = detected.cache_key().into();
+        for _ in 0..2 {
+            let mut reader = ByteOrdered::reopen_with(&path, detected).unwrap();
+            assert_eq!(reader.read_u16().unwrap(), 500);
+            assert_eq!(reader.read_u16().unwrap(), 501);
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_negotiate() {
+        let native = Endianness::native();
+        for &order in &[native, native.to_opposite()] {
+            let mut stream = ByteOrdered::negotiate(Peer::new(order), 0x0102_0304).unwrap();
+            assert_eq!(stream.endianness(), order);
+            assert_eq!(stream.read_u16().unwrap(), 0xBEEF);
+        }
+
+        let err = ByteOrdered::negotiate(Peer::new(native), 0x1234_3412).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        // a peer which does not follow the protocol
+        let mut peer = Peer::new(native);
+        peer.reply = vec![0xFF; 4];
+        let stream = ByteOrdered::negotiate(peer, 0x0102_0304);
+        assert_eq!(stream.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_order_tag_round_trip() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_order_tag().unwrap();
+            writer.write_u32(0x0102_0304).unwrap();
+            let data = writer.into_inner();
+            let expected = if e == Endianness::Big {
+                [0xFE, 0xFF]
+            } else {
+                [0xFF, 0xFE]
+            };
+            assert_eq!(data[..2], expected);
+
+            let mut reader = ByteOrdered::detect_u16_bom(&data[..]).unwrap();
+            assert_eq!(reader.endianness(), e);
+            assert_eq!(reader.read_u32().unwrap(), 0x0102_0304);
+        }
+        let mut writer = ByteOrdered::be(Vec::new());
+        writer.write_order_tag().unwrap();
+        assert_eq!(writer.into_inner(), [0xFE, 0xFF]);
+
+        let err = ByteOrdered::detect_u16_bom(&[0xFE, 0xFE][..]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let err = ByteOrdered::detect_u16_bom(&[0xFE][..]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+}
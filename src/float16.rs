@@ -0,0 +1,117 @@
+//! Reading and writing of half-precision floating point numbers
+//! from the `half` crate.
+
+use half::f16;
+use std::io::{Read, Result as IoResult, Write};
+use {ByteOrdered, Endian};
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: Read,
+    E: Endian,
+{
+    /// Reads a IEEE754 half-precision (2 bytes) floating point number from
+    /// the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate half;
+    /// # extern crate byteordered;
+    /// use byteordered::ByteOrdered;
+    /// use half::f16;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x3C, 0x00, 0xC0, 0x00][..]);
+    /// assert_eq!(rdr.read_f16()?, f16::ONE);
+    /// assert_eq!(rdr.read_f16()?, f16::from_f32(-2.));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #   run().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    pub fn read_f16(&mut self) -> IoResult<f16> {
+        let e = self.endianness();
+        e.read_f16(self.inner_mut())
+    }
+
+    /// Reads a sequence of IEEE754 half-precision (2 bytes) floating point numbers
+    /// from the underlying reader.
+    ///
+    /// The given buffer is either filled completely or an error is returned.
+    /// If an error is returned,
+    /// the contents of `dst` are unspecified.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    pub fn read_f16_into(&mut self, dst: &mut [f16]) -> IoResult<()> {
+        let e = self.endianness();
+        e.read_f16_into(self.inner_mut(), dst)
+    }
+}
+
+impl<W, E> ByteOrdered<W, E>
+where
+    W: Write,
+    E: Endian,
+{
+    /// Writes a IEEE754 half-precision (2 bytes) floating point number to
+    /// the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_f16(&mut self, x: f16) -> IoResult<()> {
+        let e = self.endianness();
+        e.write_f16(self.inner_mut(), x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::Endianness;
+    use half::f16;
+    use wrap::ByteOrdered;
+
+    #[test]
+    fn test_f16() {
+        let values = [f16::ONE, f16::from_f32(-2.), f16::MAX, f16::NEG_INFINITY];
+        for &(e, first) in &[
+            (Endianness::Little, [0x00, 0x3C]),
+            (Endianness::Big, [0x3C, 0x00]),
+        ] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            for &v in &values {
+                writer.write_f16(v).unwrap();
+            }
+            let data = writer.into_inner();
+            assert_eq!(data.len(), 8);
+            assert_eq!(data[..2], first);
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_f16().unwrap(), f16::ONE);
+            let mut rest = [f16::ZERO; 3];
+            reader.read_f16_into(&mut rest).unwrap();
+            assert_eq!(rest, values[1..]);
+
+            let mut reader = ByteOrdered::runtime(&data[..7], e);
+            let mut all = [f16::ZERO; 4];
+            assert!(reader.read_f16_into(&mut all).is_err());
+        }
+    }
+}
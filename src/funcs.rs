@@ -0,0 +1,336 @@
+//! Free functions for reading or writing a single value in a given byte order.
+//!
+//! These are thin wrappers over the methods of [`Endian`],
+//! for one-off reads and writes where creating a [`ByteOrdered`]
+//! would be too much ceremony.
+//!
+//! [`Endian`]: ../trait.Endian.html
+//! [`ByteOrdered`]: ../struct.ByteOrdered.html
+
+use std::io::{Read, Result as IoResult, Write};
+use Endian;
+
+/// Private macro for free functions
+/// which delegate a read to the same method of [`Endian`].
+macro_rules! fn_read {
+    ($(#[$attr:meta])* $method:ident, $out:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method<R, E>(reader: R, endianness: E) -> IoResult<$out>
+        where
+            R: Read,
+            E: Endian,
+        {
+            endianness.$method(reader)
+        }
+    };
+}
+
+/// Private macro for free functions
+/// which delegate a write to the same method of [`Endian`].
+macro_rules! fn_write {
+    ($(#[$attr:meta])* $method:ident, $out:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method<W, E>(writer: W, v: $out, endianness: E) -> IoResult<()>
+        where
+            W: Write,
+            E: Endian,
+        {
+            endianness.$method(writer, v)
+        }
+    };
+}
+
+fn_read!(
+    /// Reads a signed 16 bit integer from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_i16, i16
+);
+fn_read!(
+    /// Reads an unsigned 16 bit integer from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_u16, u16
+);
+fn_read!(
+    /// Reads a signed 32 bit integer from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_i32, i32
+);
+fn_read!(
+    /// Reads an unsigned 32 bit integer from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::Endianness;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut data = &[0x00, 0x00, 0x01, 0x02, 0x02, 0x01, 0x00, 0x00][..];
+    /// assert_eq!(byteordered::read_u32(&mut data, Endianness::Big)?, 0x0102);
+    /// assert_eq!(byteordered::read_u32(&mut data, Endianness::Little)?, 0x0102);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_u32, u32
+);
+fn_read!(
+    /// Reads a signed 64 bit integer from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_i64, i64
+);
+fn_read!(
+    /// Reads an unsigned 64 bit integer from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_u64, u64
+);
+fn_read!(
+    /// Reads a signed 128 bit integer from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_i128, i128
+);
+fn_read!(
+    /// Reads an unsigned 128 bit integer from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_u128, u128
+);
+fn_read!(
+    /// Reads a IEEE754 single-precision (4 bytes) floating point number
+    /// from the given reader in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_f32, f32
+);
+fn_read!(
+    /// Reads a IEEE754 double-precision (8 bytes) floating point number
+    /// from the given reader in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    read_f64, f64
+);
+
+fn_write!(
+    /// Writes a signed 16 bit integer to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_i16, i16
+);
+fn_write!(
+    /// Writes an unsigned 16 bit integer to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_u16, u16
+);
+fn_write!(
+    /// Writes a signed 32 bit integer to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_i32, i32
+);
+fn_write!(
+    /// Writes an unsigned 32 bit integer to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::Endianness;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut out = Vec::new();
+    /// byteordered::write_u32(&mut out, 0x0102, Endianness::Big)?;
+    /// byteordered::write_u32(&mut out, 0x0102, Endianness::Little)?;
+    /// assert_eq!(out, [0x00, 0x00, 0x01, 0x02, 0x02, 0x01, 0x00, 0x00]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_u32, u32
+);
+fn_write!(
+    /// Writes a signed 64 bit integer to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_i64, i64
+);
+fn_write!(
+    /// Writes an unsigned 64 bit integer to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_u64, u64
+);
+fn_write!(
+    /// Writes a signed 128 bit integer to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_i128, i128
+);
+fn_write!(
+    /// Writes an unsigned 128 bit integer to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_u128, u128
+);
+fn_write!(
+    /// Writes a IEEE754 single-precision (4 bytes) floating point number
+    /// to the given writer in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_f32, f32
+);
+fn_write!(
+    /// Writes a IEEE754 double-precision (8 bytes) floating point number
+    /// to the given writer in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    write_f64, f64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base::{Endianness, StaticEndianness};
+    use byteorder::LittleEndian;
+
+    #[test]
+    fn test_round_trip() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut out = Vec::new();
+            write_i16(&mut out, -2, e).unwrap();
+            write_u64(&mut out, 0x0102_0304_0506_0708, e).unwrap();
+            write_f64(&mut out, 1.5, e).unwrap();
+            write_u128(&mut out, 7, e).unwrap();
+
+            let mut data = &out[..];
+            assert_eq!(read_i16(&mut data, e).unwrap(), -2);
+            assert_eq!(read_u64(&mut data, e).unwrap(), 0x0102_0304_0506_0708);
+            assert_eq!(read_f64(&mut data, e).unwrap(), 1.5);
+            assert_eq!(read_u128(&mut data, e).unwrap(), 7);
+            assert!(data.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_same_as_endian_methods() {
+        let mut out = Vec::new();
+        write_u32(
+            &mut out,
+            0xDEAD_BEEF,
+            StaticEndianness::<LittleEndian>::new(),
+        )
+        .unwrap();
+        let mut expected = Vec::new();
+        Endianness::Little
+            .write_u32(&mut expected, 0xDEAD_BEEF)
+            .unwrap();
+        assert_eq!(out, expected);
+        assert_eq!(read_u32(&out[..], Endianness::Little).unwrap(), 0xDEAD_BEEF);
+    }
+}
@@ -0,0 +1,535 @@
+//! Reading of multidimensional arrays from the `ndarray` crate.
+
+use byteorder::ReadBytesExt;
+use ndarray::{Array1, Array2};
+use std::cmp::min;
+use std::io::{Error as IoError, ErrorKind, Result as IoResult};
+use std::mem::size_of;
+use {ByteOrdered, Endian};
+
+/// Private macro for reading a one-dimensional array of primitive values.
+macro_rules! fn_read_array1 {
+    ($(#[$attr:meta])* $method:ident, $read_into:ident, $ty:ty) => {
+        $(#[$attr])*
+        pub fn $method(&mut self, len: usize) -> IoResult<Array1<$ty>> {
+            let len = element_count((len, 1), size_of::<$ty>())?;
+            let data = read_chunked(len, |chunk| self.$read_into(chunk))?;
+            Ok(Array1::from(data))
+        }
+    };
+}
+
+/// Private macro for reading a two-dimensional array of primitive values
+/// in row-major order.
+macro_rules! fn_read_array2 {
+    ($(#[$attr:meta])* $method:ident, $read_into:ident, $ty:ty) => {
+        $(#[$attr])*
+        pub fn $method(&mut self, shape: (usize, usize)) -> IoResult<Array2<$ty>> {
+            let len = element_count(shape, size_of::<$ty>())?;
+            let data = read_chunked(len, |chunk| self.$read_into(chunk))?;
+            Array2::from_shape_vec(shape, data)
+                .map_err(|e| IoError::new(ErrorKind::InvalidInput, e))
+        }
+    };
+}
+
+/// Calculates the number of elements in an array of the given shape,
+/// failing if the array would not fit in memory.
+fn element_count(shape: (usize, usize), element_size: usize) -> IoResult<usize> {
+    let max_len = isize::MAX as usize / element_size;
+    shape
+        .0
+        .checked_mul(shape.1)
+        .filter(|&len| len <= max_len)
+        .ok_or_else(|| IoError::new(ErrorKind::InvalidInput, "array shape is too large"))
+}
+
+/// The maximum number of bytes by which an array grows
+/// before more data is read into it.
+const CHUNK_SIZE: usize = 0x1_0000;
+
+/// Reads `len` values in chunks of bounded size,
+/// so that memory is only committed as the data arrives
+/// and a length larger than the data available fails early.
+fn read_chunked<T, F>(len: usize, mut read_into: F) -> IoResult<Vec<T>>
+where
+    T: Copy + Default,
+    F: FnMut(&mut [T]) -> IoResult<()>,
+{
+    let chunk_len = CHUNK_SIZE / size_of::<T>();
+    let mut data = Vec::with_capacity(min(len, chunk_len));
+    while data.len() < len {
+        let start = data.len();
+        data.resize(start + min(len - start, chunk_len), T::default());
+        read_into(&mut data[start..])?;
+    }
+    Ok(data)
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: ReadBytesExt,
+    E: Endian,
+{
+    fn_read_array1!(
+        /// Reads `len` signed 16 bit integers from the underlying reader
+        /// into a one-dimensional array.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a `len` larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array1_i16,
+        read_i16_into,
+        i16
+    );
+    fn_read_array1!(
+        /// Reads `len` unsigned 16 bit integers from the underlying reader
+        /// into a one-dimensional array.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a `len` larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array1_u16,
+        read_u16_into,
+        u16
+    );
+    fn_read_array1!(
+        /// Reads `len` signed 32 bit integers from the underlying reader
+        /// into a one-dimensional array.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a `len` larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array1_i32,
+        read_i32_into,
+        i32
+    );
+    fn_read_array1!(
+        /// Reads `len` unsigned 32 bit integers from the underlying reader
+        /// into a one-dimensional array.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a `len` larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array1_u32,
+        read_u32_into,
+        u32
+    );
+    fn_read_array1!(
+        /// Reads `len` signed 64 bit integers from the underlying reader
+        /// into a one-dimensional array.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a `len` larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array1_i64,
+        read_i64_into,
+        i64
+    );
+    fn_read_array1!(
+        /// Reads `len` unsigned 64 bit integers from the underlying reader
+        /// into a one-dimensional array.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a `len` larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array1_u64,
+        read_u64_into,
+        u64
+    );
+    fn_read_array1!(
+        /// Reads `len` IEEE754 single-precision (4 bytes) floating point numbers
+        /// from the underlying reader into a one-dimensional array.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a `len` larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array1_f32,
+        read_f32_into,
+        f32
+    );
+    fn_read_array1!(
+        /// Reads `len` IEEE754 double-precision (8 bytes) floating point numbers
+        /// from the underlying reader into a one-dimensional array.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a `len` larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array1_f64,
+        read_f64_into,
+        f64
+    );
+
+    fn_read_array2!(
+        /// Reads signed 16 bit integers from the underlying reader
+        /// into a two-dimensional array of the given shape,
+        /// in row-major order.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a shape larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array2_i16,
+        read_i16_into,
+        i16
+    );
+    fn_read_array2!(
+        /// Reads unsigned 16 bit integers from the underlying reader
+        /// into a two-dimensional array of the given shape,
+        /// in row-major order.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a shape larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array2_u16,
+        read_u16_into,
+        u16
+    );
+    fn_read_array2!(
+        /// Reads signed 32 bit integers from the underlying reader
+        /// into a two-dimensional array of the given shape,
+        /// in row-major order.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a shape larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array2_i32,
+        read_i32_into,
+        i32
+    );
+    fn_read_array2!(
+        /// Reads unsigned 32 bit integers from the underlying reader
+        /// into a two-dimensional array of the given shape,
+        /// in row-major order.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a shape larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array2_u32,
+        read_u32_into,
+        u32
+    );
+    fn_read_array2!(
+        /// Reads signed 64 bit integers from the underlying reader
+        /// into a two-dimensional array of the given shape,
+        /// in row-major order.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a shape larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array2_i64,
+        read_i64_into,
+        i64
+    );
+    fn_read_array2!(
+        /// Reads unsigned 64 bit integers from the underlying reader
+        /// into a two-dimensional array of the given shape,
+        /// in row-major order.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a shape larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array2_u64,
+        read_u64_into,
+        u64
+    );
+    fn_read_array2!(
+        /// Reads IEEE754 single-precision (4 bytes) floating point numbers
+        /// from the underlying reader
+        /// into a two-dimensional array of the given shape,
+        /// in row-major order.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a shape larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array2_f32,
+        read_f32_into,
+        f32
+    );
+    fn_read_array2!(
+        /// Reads IEEE754 double-precision (8 bytes) floating point numbers
+        /// from the underlying reader
+        /// into a two-dimensional array of the given shape,
+        /// in row-major order.
+        ///
+        /// The array is filled in chunks of bounded size,
+        /// so that a shape larger than the data available
+        /// fails once the data runs out,
+        /// instead of allocating the whole array up front.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned
+        /// without reading anything
+        /// if the array would not fit in memory.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # extern crate byteordered;
+        /// # extern crate ndarray;
+        /// use byteordered::ByteOrdered;
+        /// use ndarray::arr2;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let mut wtr = ByteOrdered::be(Vec::new());
+        /// for v in &[1., 2., 3., 4., 5., 6.] {
+        ///     wtr.write_f64(*v)?;
+        /// }
+        /// let data = wtr.into_inner();
+        /// let mut rdr = ByteOrdered::be(&data[..]);
+        /// let array = rdr.read_array2_f64((2, 3))?;
+        /// assert_eq!(array, arr2(&[[1., 2., 3.], [4., 5., 6.]]));
+        /// # Ok(())
+        /// # }
+        /// # fn main() {
+        /// # run().unwrap();
+        /// # }
+        /// ```
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        read_array2_f64,
+        read_f64_into,
+        f64
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use base::Endianness;
+    use ndarray::{arr1, arr2};
+    use std::io::ErrorKind;
+    use wrap::ByteOrdered;
+
+    #[test]
+    fn test_read_array2_round_trip() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            for v in &[0.5, -1.0, 2.25, 1e10] {
+                writer.write_f64(*v).unwrap();
+            }
+            for v in &[7, 8, 9] {
+                writer.write_u16(*v).unwrap();
+            }
+            let data = writer.into_inner();
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let array = reader.read_array2_f64((2, 2)).unwrap();
+            assert_eq!(array, arr2(&[[0.5, -1.0], [2.25, 1e10]]));
+            let array = reader.read_array1_u16(3).unwrap();
+            assert_eq!(array, arr1(&[7, 8, 9]));
+        }
+    }
+
+    #[test]
+    fn test_read_array2_invalid_shape() {
+        let data: &[u8] = &[0; 8];
+        let mut reader = ByteOrdered::le(data);
+        let e = reader.read_array2_u32((usize::MAX, 2)).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        let e = reader.read_array2_u32((3, 1)).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        let array = ByteOrdered::le(data).read_array2_u32((0, 5)).unwrap();
+        assert_eq!(array.dim(), (0, 5));
+    }
+
+    #[test]
+    fn test_read_array_oversized() {
+        let data: &[u8] = &[0; 8];
+        let e = ByteOrdered::le(data)
+            .read_array1_u64(usize::MAX)
+            .unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        let e = ByteOrdered::le(data)
+            .read_array2_f32((usize::MAX / 4, 2))
+            .unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+
+        // representable, but far larger than the data,
+        // so this fails without allocating the whole array
+        let e = ByteOrdered::le(data)
+            .read_array1_u32(isize::MAX as usize / 4)
+            .unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        let e = ByteOrdered::le(data)
+            .read_array2_f64((isize::MAX as usize / 8, 1))
+            .unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+
+        // values spanning several chunks
+        let values: Vec<u16> = (0..100_000).map(|i| i as u16).collect();
+        let mut writer = ByteOrdered::be(Vec::new());
+        writer.write_u16_into(&values).unwrap();
+        let data = writer.into_inner();
+        let array = ByteOrdered::be(&data[..])
+            .read_array1_u16(values.len())
+            .unwrap();
+        assert_eq!(array.to_vec(), values);
+    }
+}
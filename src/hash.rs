@@ -0,0 +1,128 @@
+//! Adapter module for feeding typed data into hashers.
+
+use std::hash::Hasher;
+use std::io::{Result as IoResult, Write};
+
+/// Adapter which turns a [`Hasher`] into a writer.
+///
+/// Every byte written is fed to the hasher,
+/// so that a [`ByteOrdered`] writer over this adapter
+/// hashes structured data in an explicit byte order.
+/// This makes the resulting hash independent from the platform's endianness,
+/// unlike the [`Hash`] implementations of primitive integers.
+///
+/// Writing to this adapter never fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{ByteOrdered, HasherWriter};
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// # fn run() -> std::io::Result<()> {
+/// let mut hasher = DefaultHasher::new();
+/// let mut wtr = ByteOrdered::le(HasherWriter(&mut hasher));
+/// wtr.write_u32(0xDEAD_BEEF)?;
+/// wtr.write_f64(0.5)?;
+/// let hash = hasher.finish();
+/// # let _ = hash;
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+///
+/// [`Hasher`]: https://doc.rust-lang.org/std/hash/trait.Hasher.html
+/// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+/// [`ByteOrdered`]: struct.ByteOrdered.html
+#[derive(Debug, Default, Clone)]
+pub struct HasherWriter<H>(pub H);
+
+impl<H> HasherWriter<H>
+where
+    H: Hasher,
+{
+    /// Returns the hash value of the data written so far.
+    #[inline]
+    pub fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    /// Recovers the inner hasher.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.0
+    }
+}
+
+impl<H> Write for HasherWriter<H>
+where
+    H: Hasher,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0.write(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> IoResult<()> {
+        self.0.write(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HasherWriter;
+    use base::Endianness;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+    use wrap::ByteOrdered;
+
+    fn hash_values(endianness: Endianness) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        {
+            let mut writer = ByteOrdered::runtime(HasherWriter(&mut hasher), endianness);
+            writer.write_u16(0x1234).unwrap();
+            writer.write_u32(0x5678_9ABC).unwrap();
+            writer.write_i64(-2).unwrap();
+        }
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash_in_explicit_order() {
+        // hashing the same values in little endian
+        // must be equivalent to hashing their little endian encoding,
+        // regardless of the platform
+        let mut expected = DefaultHasher::new();
+        expected.write(&[0x34, 0x12, 0xBC, 0x9A, 0x78, 0x56]);
+        expected.write(&[0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(hash_values(Endianness::Little), expected.finish());
+
+        let mut expected = DefaultHasher::new();
+        expected.write(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+        expected.write(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+        assert_eq!(hash_values(Endianness::Big), expected.finish());
+
+        assert_ne!(
+            hash_values(Endianness::Little),
+            hash_values(Endianness::Big)
+        );
+    }
+
+    #[test]
+    fn test_finish_and_into_inner() {
+        let mut writer = ByteOrdered::be(HasherWriter(DefaultHasher::new()));
+        writer.write_u32(7).unwrap();
+        let (adapter, _) = writer.into_parts();
+        let hash = adapter.finish();
+        assert_eq!(adapter.into_inner().finish(), hash);
+    }
+}
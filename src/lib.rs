@@ -103,25 +103,94 @@
 //! # }
 //! ```
 //!
+//! The [`prelude`] module re-exports the most commonly used items,
+//! so that `use byteordered::prelude::*;` is enough for most code.
+//!
 //! # Features
 //!
 //! This library requires the standard library (`no_std` is currently not
 //! supported).
 //!
+//! - `flate2`: enables reading length-prefixed blocks compressed with
+//!   deflate, through `read_deflate_block`.
+//! - `half`: enables reading and writing half-precision floating point
+//!   numbers of the [`half`] crate, through `read_f16` and `write_f16`.
+//! - `ndarray`: enables reading data directly into [`ndarray`] arrays,
+//!   through methods such as `read_array1_f32` and `read_array2_f64`.
+//! - `arrayvec`: enables reading data into stack allocated [`ArrayVec`]s,
+//!   through `read_u32_into_arrayvec`.
+//! - `read_buf`: forwards `Read::read_buf` to the inner reader,
+//!   so that reading into a `BorrowedBuf` does not zero its memory.
+//!   The `read_*_into_uninit` methods then also skip zeroing their buffer.
+//!   This requires a nightly compiler.
+//! - `can_vector`: forwards `Read::is_read_vectored`
+//!   and `Write::is_write_vectored` to the inner reader or writer.
+//!   This requires a nightly compiler.
+//!
 //! [`byteorder`]: https://docs.rs/byteorder
+//! [`half`]: https://docs.rs/half/1.8
+//! [`ndarray`]: https://docs.rs/ndarray
+//! [`ArrayVec`]: https://docs.rs/arrayvec/0.7/arrayvec/struct.ArrayVec.html
 //! [`Endian`]: trait.Endian.html
 //! [`Endianness`]: enum.Endianness.html
 //! [`ByteOrdered`]: struct.ByteOrdered.html
 //! [`with_order!`]: macro.with_order.html
+//! [`prelude`]: prelude/index.html
 #![warn(missing_docs)]
+#![cfg_attr(feature = "read_buf", feature(read_buf, core_io_borrowed_buf))]
+#![cfg_attr(feature = "can_vector", feature(can_vector))]
 
+#[cfg(feature = "arrayvec")]
+extern crate arrayvec;
 pub extern crate byteorder;
+#[cfg(feature = "flate2")]
+extern crate flate2;
+#[cfg(feature = "half")]
+extern crate half;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
 
 mod base;
+#[cfg(feature = "arrayvec")]
+mod bounded;
+mod checksum;
+mod codec;
+#[cfg(feature = "flate2")]
+mod deflate;
+mod detect;
+#[cfg(feature = "half")]
+mod float16;
+mod funcs;
+#[cfg(feature = "ndarray")]
+mod grid;
+mod hash;
+mod limit;
+mod object;
+pub mod prelude;
+mod primitive;
+mod tagged;
+mod tracking;
 mod wrap;
 
-pub use base::{Endian, Endianness, StaticEndianness};
-pub use wrap::ByteOrdered;
+pub use base::{Endian, Endianness, FromByteOrder, InvalidEndiannessTag, StaticEndianness};
+pub use checksum::{Crc32Writer, InternetChecksum};
+pub use codec::ChannelOrder;
+pub use detect::{score_endianness, score_endianness_with, DetectedEndianness};
+pub use funcs::{
+    read_f32, read_f64, read_i128, read_i16, read_i32, read_i64, read_u128, read_u16, read_u32,
+    read_u64, write_f32, write_f64, write_i128, write_i16, write_i32, write_i64, write_u128,
+    write_u16, write_u32, write_u64,
+};
+pub use hash::HasherWriter;
+pub use limit::LimitedCounting;
+pub use object::{EndianRead, EndianWrite};
+pub use primitive::Primitive;
+pub use tagged::TaggedReader;
+pub use tracking::ErrorTracking;
+pub use wrap::{
+    BeReader, BeWriter, ByteOrdered, Checkpoint, LeReader, LeWriter, LenWidth, NumberFormat,
+    ResumeState, RuntimeStream,
+};
 
 /// Creates a monomorphized scope for reading or writing with run-time byte
 /// order awareness.
@@ -185,6 +254,30 @@ pub use wrap::ByteOrdered;
 /// # }
 /// ```
 ///
+/// To move an owned reader or writer into the scope and get it back
+/// afterwards, prefix the source with `owned`. The macro then evaluates to a
+/// tuple of the scope's result and the inner value, as retrieved with
+/// `into_inner()`.
+///
+/// ```
+/// # #[macro_use] extern crate byteordered;
+/// # use byteordered::Endianness;
+/// # fn get_endianness() -> Endianness { Endianness::Little }
+/// # fn run() -> Result<(), ::std::io::Error> {
+/// let e: Endianness = get_endianness();
+/// let (len, sink) = with_order!(owned Vec::new(), e, |dest| {
+///     dest.write_u16(0xEEFF)?;
+///     dest.inner_mut().len()
+/// });
+/// assert_eq!(len, 2);
+/// assert_eq!(sink, vec![0xFF, 0xEE]);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #   run().unwrap();
+/// # }
+/// ```
+///
 /// One might think that this always improves performance, since a
 /// runtime-bound `ByteOrdered` with a sequence of reads/writes would expand
 /// into one check for each method call:
@@ -215,47 +308,198 @@ pub use wrap::ByteOrdered;
 /// [`Endianness`]: enum.Endianness.html
 #[macro_export]
 macro_rules! with_order {
+    (owned $src: expr, $endianness: expr, |$bo: ident| $e: expr ) => {
+        match $endianness {
+            $crate::Endianness::Big => {
+                let mut $bo = $crate::ByteOrdered::new(
+                    $src,
+                    $crate::StaticEndianness::<$crate::byteorder::BigEndian>::default());
+                let r = $e;
+                (r, $bo.into_inner())
+            }
+            $crate::Endianness::Little => {
+                let mut $bo = $crate::ByteOrdered::new(
+                    $src,
+                    $crate::StaticEndianness::<$crate::byteorder::LittleEndian>::default());
+                let r = $e;
+                (r, $bo.into_inner())
+            }
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("unsupported byte order"),
+        }
+    };
     ($byteordered: expr, |$bo: ident| $e: expr) => {
         {
             let b = $byteordered;
             let e = b.endianness();
-            with_order!(b.into_inner(), e, |$bo| $e)
+            $crate::with_order!(b.into_inner(), e, |$bo| $e)
         }
     };
     ( ($($src: expr ),*), $endianness: expr, |$($bo: ident ),*| $e: expr ) => {
         match $endianness {
-            Endianness::Big => {
+            $crate::Endianness::Big => {
                 $(
-                let mut $bo = ::byteordered::ByteOrdered::new(
+                let mut $bo = $crate::ByteOrdered::new(
                     $src,
-                    ::byteordered::StaticEndianness::<::byteordered::byteorder::BigEndian>::default());
+                    $crate::StaticEndianness::<$crate::byteorder::BigEndian>::default());
                 )*
                 $e
             }
-            Endianness::Little => {
+            $crate::Endianness::Little => {
                 $(
-                let mut $bo = ::byteordered::ByteOrdered::new(
+                let mut $bo = $crate::ByteOrdered::new(
                     $src,
-                    ::byteordered::StaticEndianness::<::byteordered::byteorder::LittleEndian>::default());
+                    $crate::StaticEndianness::<$crate::byteorder::LittleEndian>::default());
                 )*
                 $e
             }
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("unsupported byte order"),
         }
     };
     ($src: expr, $endianness: expr, |$bo: ident| $e: expr ) => {
         match $endianness {
-            Endianness::Big => {
-                let mut $bo = ::byteordered::ByteOrdered::new(
+            $crate::Endianness::Big => {
+                let mut $bo = $crate::ByteOrdered::new(
                     $src,
-                    ::byteordered::StaticEndianness::<::byteordered::byteorder::BigEndian>::default());
+                    $crate::StaticEndianness::<$crate::byteorder::BigEndian>::default());
                 $e
             }
-            Endianness::Little => {
-                let mut $bo = ::byteordered::ByteOrdered::new(
+            $crate::Endianness::Little => {
+                let mut $bo = $crate::ByteOrdered::new(
                     $src,
-                    ::byteordered::StaticEndianness::<::byteordered::byteorder::LittleEndian>::default());
+                    $crate::StaticEndianness::<$crate::byteorder::LittleEndian>::default());
                 $e
             }
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("unsupported byte order"),
+        }
+    };
+}
+
+/// Creates a scope for reading or writing with a byte order
+/// known at compile time.
+///
+/// This is the counterpart of [`with_order!`] for a [`StaticEndianness`]:
+/// the source is bound to a `ByteOrdered` with that static endianness
+/// as is, with no `match` on a run-time value.
+/// Prefer it over [`with_order!`] when the byte order is fixed by the format,
+/// but the scope is still desired,
+/// for instance to keep the same code shape as other scopes
+/// whose byte order is only known at run-time.
+/// Passing a run-time [`Endianness`] is rejected at compile time.
+///
+/// As with [`with_order!`],
+/// multiple sources can be passed in a literal tuple expression.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate byteordered;
+/// use byteordered::StaticEndianness;
+/// use byteordered::byteorder::BigEndian;
+///
+/// # fn run() -> Result<(), ::std::io::Error> {
+/// let data: &[u8] = &[0x00, 0x2A, 0x00, 0x00, 0x00, 0x07];
+/// let (tag, len) = with_static_order!(data, StaticEndianness::<BigEndian>::new(), |rd| {
+///     (rd.read_u16()?, rd.read_u32()?)
+/// });
+/// assert_eq!((tag, len), (42, 7));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #   run().unwrap();
+/// # }
+/// ```
+///
+/// [`with_order!`]: macro.with_order.html
+/// [`Endianness`]: enum.Endianness.html
+/// [`StaticEndianness`]: struct.StaticEndianness.html
+#[macro_export]
+macro_rules! with_static_order {
+    ( ($($src: expr ),*), $endianness: expr, |$($bo: ident ),*| $e: expr ) => {
+        {
+            let endianness = $endianness;
+            $(
+            let mut $bo: $crate::ByteOrdered<_, $crate::StaticEndianness<_>> =
+                $crate::ByteOrdered::new($src, endianness);
+            )*
+            $e
+        }
+    };
+    ($src: expr, $endianness: expr, |$bo: ident| $e: expr ) => {
+        {
+            let mut $bo: $crate::ByteOrdered<_, $crate::StaticEndianness<_>> =
+                $crate::ByteOrdered::new($src, $endianness);
+            $e
+        }
+    };
+}
+
+/// Asserts that a sequence of typed reads over a byte slice
+/// yields the expected values.
+///
+/// The first argument is the data, as anything which can be viewed as a
+/// byte slice, and the second one is the byte order (either an
+/// [`Endianness`] or a [`StaticEndianness`]). What follows is a list of
+/// `type = expected` pairs, which are read in order. The supported types are
+/// the primitive integer and floating point types.
+///
+/// On failure, the macro panics with a message identifying the field by its
+/// index in the list, starting at 0. Any data left after the last field is
+/// ignored.
+///
+/// This is mostly meant for the test suites of format implementations.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate byteordered;
+/// # use byteordered::Endianness;
+/// # fn main() {
+/// let data = [0x12, 0x34, 0x56, 0x78, 0x21, 0x43];
+/// assert_reads!(&data, Endianness::Little, u32 = 0x7856_3412, u16 = 0x4321);
+/// assert_reads!(&data, Endianness::Big, u16 = 0x1234, i8 = 0x56);
+/// # }
+/// ```
+///
+/// [`Endianness`]: enum.Endianness.html
+/// [`StaticEndianness`]: struct.StaticEndianness.html
+#[macro_export]
+macro_rules! assert_reads {
+    (@read $rdr: ident, u8) => { $rdr.read_u8() };
+    (@read $rdr: ident, i8) => { $rdr.read_i8() };
+    (@read $rdr: ident, u16) => { $rdr.read_u16() };
+    (@read $rdr: ident, i16) => { $rdr.read_i16() };
+    (@read $rdr: ident, u32) => { $rdr.read_u32() };
+    (@read $rdr: ident, i32) => { $rdr.read_i32() };
+    (@read $rdr: ident, u64) => { $rdr.read_u64() };
+    (@read $rdr: ident, i64) => { $rdr.read_i64() };
+    (@read $rdr: ident, u128) => { $rdr.read_u128() };
+    (@read $rdr: ident, i128) => { $rdr.read_i128() };
+    (@read $rdr: ident, f32) => { $rdr.read_f32() };
+    (@read $rdr: ident, f64) => { $rdr.read_f64() };
+    (@fields $rdr: ident, $idx: expr, ) => {};
+    (@fields $rdr: ident, $idx: expr, $ty: ident = $expected: expr $(, $($rest: tt)*)?) => {
+        match $crate::assert_reads!(@read $rdr, $ty) {
+            Ok(value) => assert_eq!(
+                value,
+                $expected,
+                "unexpected value of field #{} ({})",
+                $idx,
+                stringify!($ty)
+            ),
+            Err(e) => panic!("failed to read field #{} ({}): {}", $idx, stringify!($ty), e),
+        }
+        $crate::assert_reads!(@fields $rdr, $idx + 1, $($($rest)*)?);
+    };
+    ($src: expr, $endianness: expr, $($fields: tt)*) => {
+        {
+            let src = &$src;
+            let mut rdr = $crate::ByteOrdered::new(
+                ::std::convert::AsRef::<[u8]>::as_ref(src),
+                $endianness);
+            $crate::assert_reads!(@fields rdr, 0usize, $($fields)*);
         }
     };
 }
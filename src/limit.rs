@@ -0,0 +1,227 @@
+//! Adapter module for readers with a byte limit.
+
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult};
+use {ByteOrdered, Endian};
+
+/// Reader adapter which caps the number of bytes read
+/// and keeps count of the bytes consumed so far.
+///
+/// This suits parsers of sub-records,
+/// which must neither read past the end of the record
+/// nor lose track of their progress within it.
+/// Once the limit is reached,
+/// the adapter behaves as if the end of the stream was reached.
+///
+/// Reads of an exact number of bytes,
+/// which include all typed reads,
+/// fail with [`ErrorKind::UnexpectedEof`]
+/// without consuming anything
+/// if they would go past the limit.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::ByteOrdered;
+///
+/// # fn run() -> std::io::Result<()> {
+/// let data = [0x00, 0x01, 0x00, 0x02, 0xFF];
+/// let mut rdr = ByteOrdered::be(&data[..]).limited_counting(4);
+/// assert_eq!(rdr.read_u16()?, 1);
+/// assert_eq!((rdr.consumed(), rdr.remaining()), (2, 2));
+/// assert!(rdr.read_u32().is_err());
+/// assert_eq!(rdr.read_u16()?, 2);
+/// assert_eq!(rdr.remaining(), 0);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+///
+/// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+#[derive(Debug)]
+pub struct LimitedCounting<R> {
+    inner: R,
+    limit: u64,
+    consumed: u64,
+}
+
+impl<R> LimitedCounting<R> {
+    /// Wraps the given reader,
+    /// allowing at most `limit` bytes to be read from it.
+    #[inline]
+    pub fn new(inner: R, limit: u64) -> Self {
+        LimitedCounting {
+            inner,
+            limit,
+            consumed: 0,
+        }
+    }
+
+    /// Obtains the number of bytes consumed so far.
+    #[inline]
+    pub fn consumed(&self) -> u64 {
+        self.consumed
+    }
+
+    /// Obtains the number of bytes which can still be read
+    /// before reaching the limit.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.limit - self.consumed
+    }
+
+    /// Obtains the maximum number of bytes
+    /// which can be read through this adapter.
+    #[inline]
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Obtains a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Obtains a mutable reference to the inner reader.
+    ///
+    /// Bytes read directly from the inner reader are not counted.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Recovers the inner reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> Read for LimitedCounting<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let max = buf
+            .len()
+            .min(self.remaining().min(usize::MAX as u64) as usize);
+        let n = self.inner.read(&mut buf[..max])?;
+        self.consumed += n as u64;
+        Ok(n)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()> {
+        if buf.len() as u64 > self.remaining() {
+            return Err(IoError::new(
+                ErrorKind::UnexpectedEof,
+                "read would exceed the byte limit",
+            ));
+        }
+        let mut filled = 0;
+        let result = loop {
+            if filled == buf.len() {
+                break Ok(());
+            }
+            match self.inner.read(&mut buf[filled..]) {
+                Ok(0) => {
+                    break Err(IoError::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    ))
+                }
+                Ok(n) => filled += n,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => break Err(e),
+            }
+        };
+        self.consumed += filled as u64;
+        result
+    }
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    E: Endian,
+{
+    /// Wraps the inner reader in a [`LimitedCounting`] adapter,
+    /// so that at most `limit` bytes can be read
+    /// and the bytes consumed are counted.
+    ///
+    /// [`LimitedCounting`]: struct.LimitedCounting.html
+    #[inline]
+    pub fn limited_counting(self, limit: u64) -> ByteOrdered<LimitedCounting<R>, E> {
+        self.map(|inner| LimitedCounting::new(inner, limit))
+    }
+}
+
+impl<R, E> ByteOrdered<LimitedCounting<R>, E>
+where
+    E: Endian,
+{
+    /// Obtains the number of bytes consumed so far
+    /// through the inner adapter.
+    #[inline]
+    pub fn consumed(&self) -> u64 {
+        self.inner_ref().consumed()
+    }
+
+    /// Obtains the number of bytes which can still be read
+    /// before reaching the limit of the inner adapter.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.inner_ref().remaining()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::Endianness;
+    use std::io::{ErrorKind, Read};
+    use wrap::ByteOrdered;
+
+    #[test]
+    fn test_limited_counting() {
+        let data: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let mut reader = ByteOrdered::runtime(data, Endianness::Little).limited_counting(6);
+        assert_eq!((reader.consumed(), reader.remaining()), (0, 6));
+
+        // up to the limit
+        assert_eq!(reader.read_u32().unwrap(), 0x0403_0201);
+        assert_eq!((reader.consumed(), reader.remaining()), (4, 2));
+
+        // past the limit, without consuming anything
+        let e = reader.read_u32().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        let mut words = [0; 2];
+        let e = reader.read_u16_into(&mut words).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        assert_eq!((reader.consumed(), reader.remaining()), (4, 2));
+
+        // at the limit
+        assert_eq!(reader.read_u16().unwrap(), 0x0605);
+        assert_eq!((reader.consumed(), reader.remaining()), (6, 0));
+        let mut buf = [0; 4];
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+        assert!(reader.read_u8().is_err());
+
+        // the rest of the data is left untouched
+        let rest = reader.into_inner().into_inner();
+        assert_eq!(rest, &[7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_limit_beyond_end_of_stream() {
+        let data: &[u8] = &[1, 2, 3];
+        let mut reader = ByteOrdered::be(data).limited_counting(8);
+        let e = reader.read_u32().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        // a short stream is still counted
+        assert_eq!(reader.consumed(), 3);
+
+        let mut reader = ByteOrdered::be(data).limited_counting(8);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, data);
+        assert_eq!((reader.consumed(), reader.remaining()), (3, 5));
+    }
+}
@@ -0,0 +1,252 @@
+//! Object-safe traits for byte order aware readers and writers.
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Result as IoResult, Write};
+use {ByteOrdered, Endian};
+
+/// Private macro for declaring the typed reading methods of [`EndianRead`].
+macro_rules! decl_read {
+    ($($(#[$attr:meta])* $method:ident, $ty:ty;)*) => {
+        $(
+        $(#[$attr])*
+        fn $method(&mut self) -> IoResult<$ty>;
+        )*
+    };
+}
+
+/// Private macro for implementing the typed reading methods of [`EndianRead`]
+/// by delegating to the inherent methods of [`ByteOrdered`].
+macro_rules! impl_read {
+    ($($method:ident, $ty:ty;)*) => {
+        $(
+        #[inline]
+        fn $method(&mut self) -> IoResult<$ty> {
+            ByteOrdered::$method(self)
+        }
+        )*
+    };
+}
+
+/// Private macro for declaring the typed writing methods of [`EndianWrite`].
+macro_rules! decl_write {
+    ($($(#[$attr:meta])* $method:ident, $ty:ty;)*) => {
+        $(
+        $(#[$attr])*
+        fn $method(&mut self, x: $ty) -> IoResult<()>;
+        )*
+    };
+}
+
+/// Private macro for implementing the typed writing methods of [`EndianWrite`]
+/// by delegating to the inherent methods of [`ByteOrdered`].
+macro_rules! impl_write {
+    ($($method:ident, $ty:ty;)*) => {
+        $(
+        #[inline]
+        fn $method(&mut self, x: $ty) -> IoResult<()> {
+            ByteOrdered::$method(self, x)
+        }
+        )*
+    };
+}
+
+/// An object-safe reader of primitive values in an assumed byte order.
+///
+/// Unlike [`ByteOrdered`] itself, this trait can be used as a trait object,
+/// so that readers of different types and byte orders
+/// can be stored and used interchangeably
+/// without losing the typed reading methods.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{ByteOrdered, EndianRead};
+///
+/// # fn run() -> std::io::Result<()> {
+/// let data = [0x00, 0x01];
+/// let mut readers: Vec<Box<dyn EndianRead>> = vec![
+///     ByteOrdered::le(&data[..]).boxed_reader(),
+///     ByteOrdered::be(&data[..]).boxed_reader(),
+/// ];
+/// assert_eq!(readers[0].read_u16()?, 0x0100);
+/// assert_eq!(readers[1].read_u16()?, 0x0001);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+///
+/// [`ByteOrdered`]: struct.ByteOrdered.html
+pub trait EndianRead: Read {
+    decl_read! {
+        /// Reads a signed 8 bit integer.
+        read_i8, i8;
+        /// Reads an unsigned 8 bit integer.
+        read_u8, u8;
+        /// Reads a signed 16 bit integer.
+        read_i16, i16;
+        /// Reads an unsigned 16 bit integer.
+        read_u16, u16;
+        /// Reads a signed 32 bit integer.
+        read_i32, i32;
+        /// Reads an unsigned 32 bit integer.
+        read_u32, u32;
+        /// Reads a signed 64 bit integer.
+        read_i64, i64;
+        /// Reads an unsigned 64 bit integer.
+        read_u64, u64;
+        /// Reads a signed 128 bit integer.
+        read_i128, i128;
+        /// Reads an unsigned 128 bit integer.
+        read_u128, u128;
+        /// Reads a IEEE754 single-precision (4 bytes) floating point number.
+        read_f32, f32;
+        /// Reads a IEEE754 double-precision (8 bytes) floating point number.
+        read_f64, f64;
+    }
+}
+
+/// An object-safe writer of primitive values in an assumed byte order.
+///
+/// This is the writing counterpart of [`EndianRead`].
+///
+/// [`EndianRead`]: trait.EndianRead.html
+pub trait EndianWrite: Write {
+    decl_write! {
+        /// Writes a signed 8 bit integer.
+        write_i8, i8;
+        /// Writes an unsigned 8 bit integer.
+        write_u8, u8;
+        /// Writes a signed 16 bit integer.
+        write_i16, i16;
+        /// Writes an unsigned 16 bit integer.
+        write_u16, u16;
+        /// Writes a signed 32 bit integer.
+        write_i32, i32;
+        /// Writes an unsigned 32 bit integer.
+        write_u32, u32;
+        /// Writes a signed 64 bit integer.
+        write_i64, i64;
+        /// Writes an unsigned 64 bit integer.
+        write_u64, u64;
+        /// Writes a signed 128 bit integer.
+        write_i128, i128;
+        /// Writes an unsigned 128 bit integer.
+        write_u128, u128;
+        /// Writes a IEEE754 single-precision (4 bytes) floating point number.
+        write_f32, f32;
+        /// Writes a IEEE754 double-precision (8 bytes) floating point number.
+        write_f64, f64;
+    }
+}
+
+impl<R, E> EndianRead for ByteOrdered<R, E>
+where
+    R: ReadBytesExt,
+    E: Endian,
+{
+    impl_read! {
+        read_i8, i8;
+        read_u8, u8;
+        read_i16, i16;
+        read_u16, u16;
+        read_i32, i32;
+        read_u32, u32;
+        read_i64, i64;
+        read_u64, u64;
+        read_i128, i128;
+        read_u128, u128;
+        read_f32, f32;
+        read_f64, f64;
+    }
+}
+
+impl<W, E> EndianWrite for ByteOrdered<W, E>
+where
+    W: WriteBytesExt,
+    E: Endian,
+{
+    impl_write! {
+        write_i8, i8;
+        write_u8, u8;
+        write_i16, i16;
+        write_u16, u16;
+        write_i32, i32;
+        write_u32, u32;
+        write_i64, i64;
+        write_u64, u64;
+        write_i128, i128;
+        write_u128, u128;
+        write_f32, f32;
+        write_f64, f64;
+    }
+}
+
+impl<T, E> ByteOrdered<T, E>
+where
+    E: Endian,
+{
+    /// Boxes this reader into an [`EndianRead`] trait object,
+    /// retaining the typed reading methods.
+    ///
+    /// [`EndianRead`]: trait.EndianRead.html
+    #[inline]
+    pub fn boxed_reader<'a>(self) -> Box<dyn EndianRead + 'a>
+    where
+        T: Read + 'a,
+        E: 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Boxes this writer into an [`EndianWrite`] trait object,
+    /// retaining the typed writing methods.
+    ///
+    /// [`EndianWrite`]: trait.EndianWrite.html
+    #[inline]
+    pub fn boxed_writer<'a>(self) -> Box<dyn EndianWrite + 'a>
+    where
+        T: Write + 'a,
+        E: 'a,
+    {
+        Box::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EndianRead, EndianWrite};
+    use base::Endianness;
+    use std::io::Read;
+    use wrap::ByteOrdered;
+
+    fn read_header(reader: &mut dyn EndianRead) -> (u16, f32) {
+        (reader.read_u16().unwrap(), reader.read_f32().unwrap())
+    }
+
+    #[test]
+    fn test_dynamic_dispatch() {
+        let mut writers: Vec<Box<dyn EndianWrite>> = vec![
+            ByteOrdered::le(Vec::new()).boxed_writer(),
+            ByteOrdered::runtime(Vec::new(), Endianness::Big).boxed_writer(),
+        ];
+        for writer in &mut writers {
+            writer.write_u16(0x0102).unwrap();
+            writer.write_f32(1.5).unwrap();
+            writer.write_all(b"!").unwrap();
+        }
+        drop(writers);
+
+        let le: &[u8] = &[0x02, 0x01, 0x00, 0x00, 0xC0, 0x3F, b'!'];
+        let be: &[u8] = &[0x01, 0x02, 0x3F, 0xC0, 0x00, 0x00, b'!'];
+        let mut readers = vec![
+            ByteOrdered::le(le).boxed_reader(),
+            ByteOrdered::runtime(be, Endianness::Big).boxed_reader(),
+        ];
+        for reader in &mut readers {
+            assert_eq!(read_header(&mut **reader), (0x0102, 1.5));
+            let mut rest = Vec::new();
+            reader.read_to_end(&mut rest).unwrap();
+            assert_eq!(rest, b"!");
+        }
+    }
+}
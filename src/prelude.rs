@@ -0,0 +1,34 @@
+//! Convenience re-exports of the most commonly used items.
+//!
+//! A single glob import brings in the wrapper type,
+//! the byte order types and traits,
+//! and the [`with_order!`] macro.
+//!
+//! # Examples
+//!
+//! ```
+//! use byteordered::prelude::*;
+//!
+//! # fn run() -> std::io::Result<()> {
+//! let mut wtr = ByteOrdered::runtime(Vec::new(), Endianness::Big);
+//! wtr.write_u16(0x0102)?;
+//! wtr.write_f32(1.5)?;
+//! let data = wtr.into_inner();
+//!
+//! let mut rdr: BeReader<&[u8]> = ByteOrdered::be(&data[..]);
+//! assert_eq!(rdr.read_u16()?, 0x0102);
+//! assert_eq!(Endianness::Big.read_f32(&data[2..])?, 1.5);
+//!
+//! let v = with_order!(&data[..], Endianness::Big, |rd| rd.read_u16())?;
+//! assert_eq!(v, 0x0102);
+//! # Ok(())
+//! # }
+//! # run().unwrap();
+//! ```
+//!
+//! [`with_order!`]: ../macro.with_order.html
+
+pub use base::{Endian, Endianness, StaticEndianness};
+pub use with_order;
+pub use with_static_order;
+pub use wrap::{BeReader, BeWriter, ByteOrdered, LeReader, LeWriter, RuntimeStream};
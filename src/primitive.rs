@@ -0,0 +1,243 @@
+//! Module for reading and writing primitive values generically.
+
+use std::io::{Read, Result as IoResult, Write};
+use {ByteOrdered, Endian};
+
+/// Trait for primitive values which can be read and written
+/// in any byte order.
+///
+/// This trait is implemented for all integer types from `u8` to `i128`,
+/// as well as for `f32` and `f64`,
+/// so that code which is generic over the primitive type
+/// can read and write it with [`ByteOrdered::read_value`]
+/// and [`ByteOrdered::write_value`].
+/// Single byte integers are read and written as they are,
+/// regardless of the byte order.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{ByteOrdered, Endian, Primitive};
+/// use std::io::Read;
+///
+/// fn read_pair<T, R, E>(rdr: &mut ByteOrdered<R, E>) -> std::io::Result<(T, T)>
+/// where
+///     T: Primitive,
+///     R: Read,
+///     E: Endian,
+/// {
+///     Ok((rdr.read_value()?, rdr.read_value()?))
+/// }
+///
+/// # fn run() -> std::io::Result<()> {
+/// let mut rdr = ByteOrdered::be(&[0x00, 0x01, 0x00, 0x02, 0x03, 0x04][..]);
+/// assert_eq!(read_pair::<u16, _, _>(&mut rdr)?, (1, 2));
+/// assert_eq!(read_pair::<i8, _, _>(&mut rdr)?, (3, 4));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+///
+/// [`ByteOrdered::read_value`]: struct.ByteOrdered.html#method.read_value
+/// [`ByteOrdered::write_value`]: struct.ByteOrdered.html#method.write_value
+pub trait Primitive: Copy + private::Sealed {
+    /// Reads a value of this type from the given reader
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    fn read<E, R>(e: E, reader: R) -> IoResult<Self>
+    where
+        E: Endian,
+        R: Read;
+
+    /// Writes this value to the given writer
+    /// in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    fn write<E, W>(self, e: E, writer: W) -> IoResult<()>
+    where
+        E: Endian,
+        W: Write;
+}
+
+/// Private macro for implementing [`Primitive`]
+/// by delegating to the methods of [`Endian`] for the same type.
+macro_rules! impl_primitive {
+    ($($ty:ty, $read:ident, $write:ident;)*) => {
+        $(
+        impl private::Sealed for $ty {}
+
+        impl Primitive for $ty {
+            #[inline]
+            fn read<E, R>(e: E, reader: R) -> IoResult<Self>
+            where
+                E: Endian,
+                R: Read,
+            {
+                e.$read(reader)
+            }
+
+            #[inline]
+            fn write<E, W>(self, e: E, writer: W) -> IoResult<()>
+            where
+                E: Endian,
+                W: Write,
+            {
+                e.$write(writer, self)
+            }
+        }
+        )*
+    };
+}
+
+impl_primitive! {
+    u8, read_u8, write_u8;
+    i8, read_i8, write_i8;
+    u16, read_u16, write_u16;
+    i16, read_i16, write_i16;
+    u32, read_u32, write_u32;
+    i32, read_i32, write_i32;
+    u64, read_u64, write_u64;
+    i64, read_i64, write_i64;
+    u128, read_u128, write_u128;
+    i128, read_i128, write_i128;
+    f32, read_f32, write_f32;
+    f64, read_f64, write_f64;
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: Read,
+    E: Endian,
+{
+    /// Reads a primitive value of any type from the underlying reader.
+    ///
+    /// This is the generic counterpart of methods such as
+    /// [`read_u32`] and [`read_f64`].
+    /// It is not named `read`,
+    /// so as not to shadow [`Read::read`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0x2A, 0x00, 0x00, 0x00, 0xFF][..]);
+    /// assert_eq!(rdr.read_value::<u32>()?, 42);
+    /// assert_eq!(rdr.read_value::<i8>()?, -1);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`read_u32`]: #method.read_u32
+    /// [`read_f64`]: #method.read_f64
+    /// [`Read::read`]: https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    pub fn read_value<T>(&mut self) -> IoResult<T>
+    where
+        T: Primitive,
+    {
+        let e = self.endianness();
+        T::read(e, self.inner_mut())
+    }
+}
+
+impl<W, E> ByteOrdered<W, E>
+where
+    W: Write,
+    E: Endian,
+{
+    /// Writes a primitive value of any type to the underlying writer.
+    ///
+    /// This is the generic counterpart of methods such as
+    /// [`write_u32`] and [`write_f64`].
+    /// It is not named `write`,
+    /// so as not to shadow [`Write::write`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`write_u32`]: #method.write_u32
+    /// [`write_f64`]: #method.write_f64
+    /// [`Write::write`]: https://doc.rust-lang.org/std/io/trait.Write.html#tymethod.write
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_value<T>(&mut self, x: T) -> IoResult<()>
+    where
+        T: Primitive,
+    {
+        let e = self.endianness();
+        x.write(e, self.inner_mut())
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Primitive;
+    use base::Endianness;
+    use std::fmt::Debug;
+    use wrap::ByteOrdered;
+
+    fn round_trip<T>(e: Endianness, values: &[T]) -> Vec<u8>
+    where
+        T: Primitive + PartialEq + Debug,
+    {
+        let mut writer = ByteOrdered::runtime(Vec::new(), e);
+        for &v in values {
+            writer.write_value(v).unwrap();
+        }
+        let data = writer.into_inner();
+
+        let mut reader = ByteOrdered::runtime(&data[..], e);
+        for v in values {
+            assert_eq!(&reader.read_value::<T>().unwrap(), v);
+        }
+        assert!(reader.read_value::<T>().is_err());
+        data
+    }
+
+    #[test]
+    fn test_primitive() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            assert_eq!(round_trip(e, &[0x12u8, 0xFF]), [0x12, 0xFF]);
+            assert_eq!(round_trip(e, &[-1i8, 5]), [0xFF, 0x05]);
+            round_trip(e, &[0x1234u16, 0]);
+            round_trip(e, &[-2i16, i16::MAX]);
+            round_trip(e, &[0xDEAD_BEEFu32]);
+            round_trip(e, &[i32::MIN, -7]);
+            round_trip(e, &[u64::MAX - 3]);
+            round_trip(e, &[-1i64 << 40]);
+            round_trip(e, &[u128::MAX - 9]);
+            round_trip(e, &[i128::MIN + 1]);
+            round_trip(e, &[1.5f32, -0.]);
+            round_trip(e, &[f64::MAX, 1e-300]);
+        }
+
+        let le = round_trip(Endianness::Little, &[0x0102u16]);
+        let be = round_trip(Endianness::Big, &[0x0102u16]);
+        assert_eq!(le, [0x02, 0x01]);
+        assert_eq!(be, [0x01, 0x02]);
+    }
+}
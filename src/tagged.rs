@@ -0,0 +1,175 @@
+//! Module for decoding records which start with a discriminating tag.
+
+use std::fmt;
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult};
+use wrap::LenWidth;
+use {ByteOrdered, Endian};
+
+/// A decoder for a single kind of record.
+type Decoder<'a, R, E, T> = Box<dyn FnMut(&mut ByteOrdered<R, E>) -> IoResult<T> + 'a>;
+
+/// A table of decoders for records preceded by a tag,
+/// built with [`ByteOrdered::tagged`].
+///
+/// Each decoder is registered for one tag value with [`on`].
+/// Reading a record with [`read`] reads the tag
+/// in the byte order of the given reader,
+/// then decodes the rest of the record
+/// with the decoder registered for that tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{ByteOrdered, LenWidth};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Shape {
+///     Circle(u16),
+///     Rect(u16, u16),
+/// }
+///
+/// # fn run() -> std::io::Result<()> {
+/// let mut shapes = ByteOrdered::tagged(LenWidth::U8)
+///     .on(1, |rdr| Ok(Shape::Circle(rdr.read_u16()?)))
+///     .on(2, |rdr| Ok(Shape::Rect(rdr.read_u16()?, rdr.read_u16()?)));
+///
+/// let mut rdr = ByteOrdered::be(&[0x02, 0x00, 0x03, 0x00, 0x04, 0x01, 0x00, 0x05][..]);
+/// assert_eq!(shapes.read(&mut rdr)?, Shape::Rect(3, 4));
+/// assert_eq!(shapes.read(&mut rdr)?, Shape::Circle(5));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+///
+/// [`ByteOrdered::tagged`]: struct.ByteOrdered.html#method.tagged
+/// [`on`]: #method.on
+/// [`read`]: #method.read
+pub struct TaggedReader<'a, R, E, T> {
+    width: LenWidth,
+    decoders: Vec<(u64, Decoder<'a, R, E, T>)>,
+}
+
+impl<'a, R, E, T> fmt::Debug for TaggedReader<'a, R, E, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tags: Vec<u64> = self.decoders.iter().map(|&(tag, _)| tag).collect();
+        f.debug_struct("TaggedReader")
+            .field("width", &self.width)
+            .field("tags", &tags)
+            .finish()
+    }
+}
+
+impl<'a, R, E, T> TaggedReader<'a, R, E, T>
+where
+    R: Read,
+    E: Endian,
+{
+    /// Registers the decoder for records with the given tag.
+    ///
+    /// A decoder registered earlier for the same tag is replaced.
+    pub fn on<F>(mut self, tag: u64, decode: F) -> Self
+    where
+        F: FnMut(&mut ByteOrdered<R, E>) -> IoResult<T> + 'a,
+    {
+        self.decoders.retain(|&(t, _)| t != tag);
+        self.decoders.push((tag, Box::new(decode)));
+        self
+    }
+
+    /// Reads a tag from the given reader,
+    /// then decodes the rest of the record
+    /// with the decoder registered for it.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`]
+    /// while reading the tag,
+    /// and any error produced by the decoder.
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if no decoder was registered for the tag,
+    /// in which case only the tag is consumed.
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read(&mut self, rdr: &mut ByteOrdered<R, E>) -> IoResult<T> {
+        let tag = match self.width {
+            LenWidth::U8 => u64::from(rdr.read_u8()?),
+            LenWidth::U16 => u64::from(rdr.read_u16()?),
+            LenWidth::U32 => u64::from(rdr.read_u32()?),
+            LenWidth::U64 => rdr.read_u64()?,
+        };
+        match self.decoders.iter_mut().find(|&&mut (t, _)| t == tag) {
+            Some(&mut (_, ref mut decode)) => decode(rdr),
+            None => Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!("unknown tag {}", tag),
+            )),
+        }
+    }
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: Read,
+    E: Endian,
+{
+    /// Creates an empty table of decoders
+    /// for records preceded by a tag of the given width.
+    ///
+    /// See [`TaggedReader`] for an example.
+    ///
+    /// [`TaggedReader`]: struct.TaggedReader.html
+    #[inline]
+    pub fn tagged<'a, T>(width: LenWidth) -> TaggedReader<'a, R, E, T> {
+        TaggedReader {
+            width,
+            decoders: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::Endianness;
+    use std::io::ErrorKind;
+    use wrap::{ByteOrdered, LenWidth};
+
+    #[test]
+    fn test_tagged() {
+        let orders = [Endianness::Big, Endianness::Little];
+        let data: Vec<Vec<u8>> = orders
+            .iter()
+            .map(|&e| {
+                let mut writer = ByteOrdered::runtime(Vec::new(), e);
+                writer.write_u16(0x0100).unwrap();
+                writer.write_u32(7).unwrap();
+                writer.write_u16(0x0002).unwrap();
+                writer.write_u64(9).unwrap();
+                writer.write_u16(0x0003).unwrap();
+                writer.write_u8(0xFF).unwrap();
+                writer.into_inner()
+            })
+            .collect();
+
+        let mut seen = Vec::new();
+        {
+            let mut table = ByteOrdered::tagged(LenWidth::U16)
+                .on(0x0100, |rdr| rdr.read_u32().map(u64::from))
+                .on(0x0002, |rdr| {
+                    seen.push(rdr.endianness());
+                    rdr.read_u64()
+                });
+
+            for (&e, data) in orders.iter().zip(&data) {
+                let mut reader = ByteOrdered::runtime(&data[..], e);
+                assert_eq!(table.read(&mut reader).unwrap(), 7);
+                assert_eq!(table.read(&mut reader).unwrap(), 9);
+                let err = table.read(&mut reader).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::InvalidData);
+                // only the unknown tag was consumed
+                assert_eq!(reader.into_inner(), &[0xFF]);
+            }
+        }
+        assert_eq!(seen, orders);
+    }
+}
@@ -0,0 +1,216 @@
+//! Adapter module for writers which defer their errors.
+
+use std::io::{Error as IoError, ErrorKind, Result as IoResult, Write};
+use {ByteOrdered, Endian};
+
+/// Writer adapter which remembers the first error
+/// encountered by the inner writer.
+///
+/// Once an error occurs,
+/// it is kept in the adapter and all subsequent writes become no-ops
+/// which report success,
+/// until the error is retrieved with [`take_error`].
+/// This suits fire-and-forget encoders,
+/// which can then check for failure once at the end.
+///
+/// Interruptions ([`ErrorKind::Interrupted`]) are not recorded,
+/// as they are meant to be retried.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::ByteOrdered;
+///
+/// let mut buf = [0u8; 6];
+/// let mut wtr = ByteOrdered::be(&mut buf[..]).error_tracking();
+/// wtr.write_u32(1).unwrap();
+/// // does not fit, the error is recorded
+/// wtr.write_u32(2).unwrap();
+/// // no-op
+/// wtr.write_u16(3).unwrap();
+/// assert!(wtr.take_error().is_some());
+/// assert!(wtr.take_error().is_none());
+/// ```
+///
+/// [`take_error`]: #method.take_error
+/// [`ErrorKind::Interrupted`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.Interrupted
+#[derive(Debug)]
+pub struct ErrorTracking<W> {
+    inner: W,
+    error: Option<IoError>,
+}
+
+impl<W> ErrorTracking<W> {
+    /// Wraps the given writer.
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        ErrorTracking { inner, error: None }
+    }
+
+    /// Retrieves the first error recorded since the last call,
+    /// if any.
+    /// Writes are no longer suppressed afterwards.
+    #[inline]
+    pub fn take_error(&mut self) -> Option<IoError> {
+        self.error.take()
+    }
+
+    /// Checks whether an error has been recorded.
+    #[inline]
+    pub fn has_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Obtains a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Obtains a mutable reference to the inner writer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Recovers the inner writer,
+    /// discarding any recorded error.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn track<T>(&mut self, result: IoResult<T>, suppressed: T) -> IoResult<T> {
+        match result {
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => result,
+            Err(e) => {
+                self.error = Some(e);
+                Ok(suppressed)
+            }
+            Ok(v) => Ok(v),
+        }
+    }
+}
+
+impl<W> Write for ErrorTracking<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        if self.error.is_some() {
+            return Ok(buf.len());
+        }
+        let result = self.inner.write(buf);
+        self.track(result, buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> IoResult<()> {
+        if self.error.is_some() {
+            return Ok(());
+        }
+        let result = self.inner.write_all(buf);
+        self.track(result, ())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        if self.error.is_some() {
+            return Ok(());
+        }
+        let result = self.inner.flush();
+        self.track(result, ())
+    }
+}
+
+impl<W, E> ByteOrdered<W, E>
+where
+    E: Endian,
+{
+    /// Wraps the inner writer in an [`ErrorTracking`] adapter,
+    /// so that the first error is recorded instead of returned,
+    /// and subsequent writes are suppressed.
+    ///
+    /// [`ErrorTracking`]: struct.ErrorTracking.html
+    #[inline]
+    pub fn error_tracking(self) -> ByteOrdered<ErrorTracking<W>, E> {
+        self.map(ErrorTracking::new)
+    }
+}
+
+impl<W, E> ByteOrdered<ErrorTracking<W>, E>
+where
+    E: Endian,
+{
+    /// Retrieves the first error recorded by the inner adapter
+    /// since the last call, if any.
+    /// Writes are no longer suppressed afterwards.
+    #[inline]
+    pub fn take_error(&mut self) -> Option<IoError> {
+        self.inner_mut().take_error()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorTracking;
+    use base::Endianness;
+    use std::io::{Error as IoError, ErrorKind, Result as IoResult, Write};
+    use wrap::ByteOrdered;
+
+    /// A writer which accepts a limited number of writes before failing.
+    struct FlakyWriter {
+        data: Vec<u8>,
+        writes_left: usize,
+    }
+
+    impl Write for FlakyWriter {
+        fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+            if self.writes_left == 0 {
+                return Err(IoError::new(ErrorKind::BrokenPipe, "no more writes"));
+            }
+            self.writes_left -= 1;
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_first_error_is_captured() {
+        let sink = FlakyWriter {
+            data: Vec::new(),
+            writes_left: 2,
+        };
+        let mut writer = ByteOrdered::runtime(sink, Endianness::Big).error_tracking();
+        writer.write_u16(0x0102).unwrap();
+        writer.write_u16(0x0304).unwrap();
+        assert!(!writer.inner_mut().has_error());
+        writer.write_u16(0x0506).unwrap();
+        assert!(writer.inner_mut().has_error());
+
+        // further writes are suppressed, even if the sink would accept them
+        writer.inner_mut().get_mut().writes_left = 10;
+        writer.write_u32(0xFFFF_FFFF).unwrap();
+        writer.flush().unwrap();
+        assert_eq!(writer.inner_mut().get_ref().data, [1, 2, 3, 4]);
+
+        let e = writer.take_error().unwrap();
+        assert_eq!(e.kind(), ErrorKind::BrokenPipe);
+        assert!(writer.take_error().is_none());
+
+        // writes resume after the error is taken
+        writer.write_u16(0x0708).unwrap();
+        let sink = writer.into_inner().into_inner();
+        assert_eq!(sink.data, [1, 2, 3, 4, 7, 8]);
+    }
+
+    #[test]
+    fn test_no_error() {
+        let mut writer = ByteOrdered::le(ErrorTracking::new(Vec::new()));
+        writer.write_u32(1).unwrap();
+        assert!(writer.take_error().is_none());
+        assert_eq!(writer.into_inner().into_inner(), [1, 0, 0, 0]);
+    }
+}
@@ -1,23 +1,141 @@
 //! Wrapper types providing byte order awareness.
 
+use base::{not_24_bit, I24_RANGE, U24_RANGE};
 use byteorder::{
     BigEndian, LittleEndian, NativeEndian, NetworkEndian, ReadBytesExt, WriteBytesExt,
 };
+use std::convert::TryFrom;
 use std::fmt::Arguments;
-use std::io::{BufRead, Read, Result as IoResult, Seek, SeekFrom, Write};
-use {Endian, Endianness, StaticEndianness};
+use std::fs::File;
+#[cfg(feature = "read_buf")]
+use std::io::BorrowedBuf;
+use std::io::{
+    BufRead, BufReader, BufWriter, Cursor, Error as IoError, ErrorKind, IntoInnerError, IoSlice,
+    IoSliceMut, Read, Result as IoResult, Seek, SeekFrom, Write,
+};
+use std::mem::{size_of, MaybeUninit};
+use std::{iter, slice};
+use {Endian, Endianness, FromByteOrder, StaticEndianness};
 
 /// Wrapper type for a reader or writer with an assumed byte order.
 ///
 /// More details can be found at the [crate level documentation][1].
 ///
+/// The bulk reading methods (such as [`read_u32_into`])
+/// request all of the bytes needed from the underlying reader at once
+/// and convert them to the assumed byte order in place,
+/// regardless of whether the byte order is known at compile time
+/// or at run time.
+/// No intermediate buffer is involved,
+/// so there is no chunk size to tune.
+/// Sequences of many small reads
+/// over sources without any buffering of their own
+/// (such as files and sockets)
+/// still benefit from wrapping the source in a [`BufReader`] first.
+///
+/// When the inner type is both a reader and a writer
+/// (such as a `TcpStream`),
+/// the typed reading and writing methods are all available
+/// on the same wrapper, with the same byte order.
+/// See also [`transfer`].
+///
+/// Like [`Read::read_exact`],
+/// all reading methods retry reads which fail with
+/// [`ErrorKind::Interrupted`],
+/// whether the byte order is known at compile time or at run time.
+///
 /// [1]: index.html
+/// [`read_u32_into`]: #method.read_u32_into
+/// [`transfer`]: #method.transfer
+/// [`BufReader`]: https://doc.rust-lang.org/std/io/struct.BufReader.html
+/// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+/// [`ErrorKind::Interrupted`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.Interrupted
 #[derive(Debug, Clone)]
 pub struct ByteOrdered<T, E> {
     inner: T,
     endianness: E,
 }
 
+/// A reader which assumes data in _little endian_.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{ByteOrdered, LeReader};
+///
+/// struct Header<'a> {
+///     source: LeReader<&'a [u8]>,
+/// }
+///
+/// # fn run() -> std::io::Result<()> {
+/// let mut header = Header {
+///     source: ByteOrdered::le(&[0x01, 0x02][..]),
+/// };
+/// assert_eq!(header.source.read_u16()?, 0x0201);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub type LeReader<R> = ByteOrdered<R, StaticEndianness<LittleEndian>>;
+
+/// A reader which assumes data in _big endian_.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{BeReader, ByteOrdered};
+///
+/// fn read_magic(rdr: &mut BeReader<&[u8]>) -> std::io::Result<u32> {
+///     rdr.read_u32()
+/// }
+///
+/// let mut rdr = ByteOrdered::be(&[0xCA, 0xFE, 0xBA, 0xBE][..]);
+/// assert_eq!(read_magic(&mut rdr).unwrap(), 0xCAFE_BABE);
+/// ```
+pub type BeReader<R> = ByteOrdered<R, StaticEndianness<BigEndian>>;
+
+/// A writer which assumes data in _little endian_.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{ByteOrdered, LeWriter};
+///
+/// let mut wtr: LeWriter<Vec<u8>> = ByteOrdered::le(Vec::new());
+/// wtr.write_u16(0x0201).unwrap();
+/// assert_eq!(wtr.into_inner(), [0x01, 0x02]);
+/// ```
+pub type LeWriter<W> = ByteOrdered<W, StaticEndianness<LittleEndian>>;
+
+/// A writer which assumes data in _big endian_.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{BeWriter, ByteOrdered};
+///
+/// let mut wtr: BeWriter<Vec<u8>> = ByteOrdered::be(Vec::new());
+/// wtr.write_u16(0x0102).unwrap();
+/// assert_eq!(wtr.into_inner(), [0x01, 0x02]);
+/// ```
+pub type BeWriter<W> = ByteOrdered<W, StaticEndianness<BigEndian>>;
+
+/// A reader or writer with a byte order only known at _run-time_.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteordered::{ByteOrdered, Endianness, RuntimeStream};
+///
+/// fn open(data: &[u8], big_endian: bool) -> RuntimeStream<&[u8]> {
+///     ByteOrdered::runtime(data, Endianness::be_iff(big_endian))
+/// }
+///
+/// let mut rdr = open(&[0x01, 0x02], true);
+/// assert_eq!(rdr.read_u16().unwrap(), 0x0102);
+/// ```
+pub type RuntimeStream<T> = ByteOrdered<T, Endianness>;
+
 impl<T, E> ByteOrdered<T, E>
 where
     E: Default,
@@ -65,6 +183,43 @@ impl<T> ByteOrdered<T, StaticEndianness<NetworkEndian>> {
     }
 }
 
+impl<T, BO> ByteOrdered<T, StaticEndianness<BO>>
+where
+    BO: FromByteOrder,
+{
+    /// Creates a new reader or writer that assumes data in the byte order
+    /// of the given [`byteorder`] marker type.
+    ///
+    /// This eases the migration of code
+    /// which is generic over a `byteorder::ByteOrder` type parameter.
+    /// The byte order is resolved at compile time,
+    /// so the resulting wrapper has a [`StaticEndianness`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, FromByteOrder, StaticEndianness};
+    /// use byteordered::byteorder::{BigEndian, LittleEndian};
+    /// use std::io::Read;
+    ///
+    /// fn read_header<BO: FromByteOrder, R: Read>(source: R) -> std::io::Result<u16> {
+    ///     let mut rdr: ByteOrdered<R, StaticEndianness<BO>> =
+    ///         ByteOrdered::from_byteorder_marker(source);
+    ///     rdr.read_u16()
+    /// }
+    ///
+    /// assert_eq!(read_header::<LittleEndian, _>(&[1, 0][..]).unwrap(), 1);
+    /// assert_eq!(read_header::<BigEndian, _>(&[1, 0][..]).unwrap(), 256);
+    /// ```
+    ///
+    /// [`byteorder`]: https://docs.rs/byteorder
+    /// [`StaticEndianness`]: struct.StaticEndianness.html
+    #[inline]
+    pub fn from_byteorder_marker(inner: T) -> Self {
+        ByteOrdered::new_default(inner)
+    }
+}
+
 impl<T> ByteOrdered<T, Endianness> {
     /// Creates a new reader or writer that assumes data in the given byte
     /// order known at _run-time_.
@@ -85,6 +240,35 @@ impl<T> ByteOrdered<T, Endianness> {
     pub fn runtime(inner: T, endianness: Endianness) -> Self {
         ByteOrdered::new(inner, endianness)
     }
+
+    /// Creates a new reader or writer that assumes data in big endian
+    /// if `is_big` is true, and little endian otherwise.
+    ///
+    /// This is equivalent to
+    /// `ByteOrdered::runtime(inner, Endianness::be_iff(is_big))`,
+    /// and suits formats which declare the byte order
+    /// with a flag in their header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Read;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut data = &[0x01, 0x00, 0x00, 0x00, 0x2A][..];
+    /// let mut flag = [0];
+    /// data.read_exact(&mut flag)?;
+    /// let mut rdr = ByteOrdered::runtime_from_flag(data, flag[0] != 0);
+    /// assert_eq!(rdr.read_u32()?, 42);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    #[inline]
+    pub fn runtime_from_flag(inner: T, is_big: bool) -> Self {
+        ByteOrdered::runtime(inner, Endianness::be_iff(is_big))
+    }
 }
 
 impl<T, E> From<(T, E)> for ByteOrdered<T, E> {
@@ -127,6 +311,52 @@ where
         self.inner
     }
 
+    /// Recovers the inner reader or writer from this wrapper,
+    /// so that the remaining layers can be unwrapped in turn.
+    ///
+    /// This is the same as [`into_inner`].
+    /// When the wrapper is layered over an adapter,
+    /// such as a decompression stream,
+    /// the recommended pattern is to finish reading through the wrapper,
+    /// call this method,
+    /// then recover the original source
+    /// through the adapter's own `into_inner`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate flate2;
+    /// # extern crate byteordered;
+    /// use byteordered::ByteOrdered;
+    /// use flate2::read::GzDecoder;
+    /// use flate2::write::GzEncoder;
+    /// use flate2::Compression;
+    /// use std::io::Write;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    /// encoder.write_all(&[0x00, 0x2A])?;
+    /// let compressed = encoder.finish()?;
+    ///
+    /// let mut rdr = ByteOrdered::be(GzDecoder::new(&compressed[..]));
+    /// assert_eq!(rdr.read_u16()?, 42);
+    /// let decoder: GzDecoder<&[u8]> = rdr.into_innermost();
+    /// // whatever the decoder did not consume is left in the source
+    /// let source: &[u8] = decoder.into_inner();
+    /// assert!(compressed.ends_with(source));
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #   run().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`into_inner`]: #method.into_inner
+    #[inline]
+    pub fn into_innermost(self) -> T {
+        self.inner
+    }
+
     /// Obtains an exclusive mutable reference to the inner reader or writer in
     /// this wrapper. Information about the assumed byte order is ignored until
     /// the reference is dropped.
@@ -135,6 +365,13 @@ where
         &mut self.inner
     }
 
+    /// Obtains a shared reference to the inner reader or writer,
+    /// for crate-internal accessors which do not need exclusive access.
+    #[inline]
+    pub(crate) fn inner_ref(&self) -> &T {
+        &self.inner
+    }
+
     /// Converts from `ByteOrdered<T, E>` to `ByteOrdered<&mut T, E>`,
     /// copying the endianness information.
     #[inline]
@@ -234,6 +471,49 @@ where
     {
         self.endianness.is_native()
     }
+
+    /// Checks whether the assumed endianness resolves to big endian.
+    #[inline]
+    pub(crate) fn is_big_endian(&self) -> bool
+    where
+        E: Endian,
+    {
+        self.endianness.is_native() == cfg!(target_endian = "big")
+    }
+
+    /// Retrieves a human readable name of the assumed byte order,
+    /// either `"little"` or `"big"`.
+    ///
+    /// The name `"native"` is never returned:
+    /// [`NativeEndian`] is an alias of one of the two byte orders,
+    /// so a wrapper created with [`native`] has the same type
+    /// as one created with [`le`] or [`be`],
+    /// and reports the system's byte order by name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, Endianness};
+    ///
+    /// let rdr = ByteOrdered::runtime(&[0u8; 0][..], Endianness::Big);
+    /// assert_eq!(rdr.endianness_name(), "big");
+    /// assert_eq!(ByteOrdered::le(&[0u8; 0][..]).endianness_name(), "little");
+    /// ```
+    ///
+    /// [`NativeEndian`]: https://docs.rs/byteorder/1/byteorder/type.NativeEndian.html
+    /// [`native`]: #method.native
+    /// [`le`]: #method.le
+    /// [`be`]: #method.be
+    #[inline]
+    pub fn endianness_name(&self) -> &'static str
+    where
+        E: Into<Endianness> + Copy,
+    {
+        match self.endianness.into() {
+            Endianness::Little => "little",
+            Endianness::Big => "big",
+        }
+    }
 }
 
 impl<R, E> Read for ByteOrdered<R, E>
@@ -259,6 +539,23 @@ where
     fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()> {
         self.inner.read_exact(buf)
     }
+
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> IoResult<usize> {
+        self.inner.read_vectored(bufs)
+    }
+
+    #[cfg(feature = "can_vector")]
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.inner.is_read_vectored()
+    }
+
+    #[cfg(feature = "read_buf")]
+    #[inline]
+    fn read_buf(&mut self, buf: ::std::io::BorrowedCursor) -> IoResult<()> {
+        self.inner.read_buf(buf)
+    }
 }
 
 impl<W, E> Write for ByteOrdered<W, E>
@@ -270,6 +567,17 @@ where
         self.inner.write(buf)
     }
 
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> IoResult<usize> {
+        self.inner.write_vectored(bufs)
+    }
+
+    #[cfg(feature = "can_vector")]
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
     #[inline]
     fn flush(&mut self) -> IoResult<()> {
         self.inner.flush()
@@ -286,6 +594,89 @@ where
     }
 }
 
+/// Private macro for reading a sequence of primitive values
+/// into a newly allocated vector.
+macro_rules! fn_read_vec {
+    ($(#[$attr:meta])* $method:ident, $into:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method(&mut self, count: usize) -> IoResult<Vec<$ty>> {
+            let mut values = vec![<$ty>::default(); count];
+            self.$into(&mut values)?;
+            Ok(values)
+        }
+    };
+}
+
+/// Fills a buffer of possibly uninitialized bytes from the given reader,
+/// returning it as a slice of initialized bytes.
+///
+/// The bytes are read in place through `Read::read_buf_exact`,
+/// without initializing them first.
+#[cfg(feature = "read_buf")]
+fn read_exact_uninit<R>(mut reader: R, buf: &mut [MaybeUninit<u8>]) -> IoResult<&mut [u8]>
+where
+    R: Read,
+{
+    let len = buf.len();
+    let ptr = buf.as_mut_ptr() as *mut u8;
+    let mut buf = BorrowedBuf::from(buf);
+    reader.read_buf_exact(buf.unfilled())?;
+    // SAFETY: `read_buf_exact` only succeeds once the whole buffer is filled,
+    // so all `len` bytes behind `ptr` are initialized.
+    Ok(unsafe { slice::from_raw_parts_mut(ptr, len) })
+}
+
+/// Fills a buffer of possibly uninitialized bytes from the given reader,
+/// returning it as a slice of initialized bytes.
+///
+/// `Read::read_exact` must not be given uninitialized memory,
+/// since an implementation is free to read from the buffer,
+/// so the buffer is zeroed before reading.
+#[cfg(not(feature = "read_buf"))]
+fn read_exact_uninit<R>(mut reader: R, buf: &mut [MaybeUninit<u8>]) -> IoResult<&mut [u8]>
+where
+    R: Read,
+{
+    for b in buf.iter_mut() {
+        *b = MaybeUninit::new(0);
+    }
+    // SAFETY: every byte of the buffer was initialized above,
+    // and `MaybeUninit<u8>` has the same layout as `u8`.
+    let bytes = unsafe { slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut u8, buf.len()) };
+    reader.read_exact(bytes)?;
+    Ok(bytes)
+}
+
+/// Private macro for reading a sequence of primitive values
+/// into a slice of possibly uninitialized memory.
+macro_rules! fn_read_into_uninit {
+    ($(#[$attr:meta])* $method:ident, $ty:ty) => {
+        $(#[$attr])*
+        pub fn $method<'a>(&mut self, dst: &'a mut [MaybeUninit<$ty>]) -> IoResult<&'a mut [$ty]> {
+            let len = dst.len() * size_of::<$ty>();
+            // SAFETY: the pointer is valid for reads and writes of `len` bytes,
+            // since it comes from a slice of `dst.len()` elements of this size,
+            // and uninitialized bytes are valid values of `MaybeUninit<u8>`.
+            let bytes = unsafe {
+                slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut MaybeUninit<u8>, len)
+            };
+            let bytes = read_exact_uninit(self.inner.by_ref(), bytes)?;
+            // convert each value to native byte order in place
+            if !self.endianness.is_native() {
+                for e in bytes.chunks_mut(size_of::<$ty>()) {
+                    e.reverse();
+                }
+            }
+            // SAFETY: every byte of `dst` was initialized by the read above,
+            // the bytes of each element are now in native byte order,
+            // any bit pattern is a valid value of this primitive type,
+            // and `MaybeUninit<T>` has the same layout as `T`.
+            Ok(unsafe { slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut $ty, dst.len()) })
+        }
+    };
+}
+
 impl<R, E> ByteOrdered<R, E>
 where
     R: ReadBytesExt,
@@ -355,6 +746,61 @@ where
         ReadBytesExt::read_u8(self)
     }
 
+    /// Reads a boolean from the underlying reader,
+    /// encoded as a single byte.
+    ///
+    /// Zero is read as `false`, and any other value as `true`.
+    /// See [`read_bool_strict`] for rejecting values other than 0 and 1.
+    /// As with `read_u8`, no byte order is involved.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::native(&[0, 1, 0xFF][..]);
+    /// assert!(!rdr.read_bool()?);
+    /// assert!(rdr.read_bool()?);
+    /// assert!(rdr.read_bool()?);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`read_bool_strict`]: #method.read_bool_strict
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    pub fn read_bool(&mut self) -> IoResult<bool> {
+        self.read_u8().map(|b| b != 0)
+    }
+
+    /// Reads a boolean from the underlying reader,
+    /// encoded as a single byte which must be either 0 or 1.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the byte is neither 0 nor 1.
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_bool_strict(&mut self) -> IoResult<bool> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            b => Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!("invalid boolean byte {:#04X}", b),
+            )),
+        }
+    }
+
     /// Reads a signed 16 bit integer from the underlying reader.
     ///
     /// # Errors
@@ -385,7 +831,9 @@ where
 
     /// Reads a sequence of signed 16 bit integers from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [i16]`, `&mut Vec<i16>` or `&mut Box<[i16]>`.
+    /// It is either filled completely or an error is returned.
     /// If an error is returned,
     /// the contents of `dst` are unspecified.
     ///
@@ -410,8 +858,12 @@ where
     /// # }
     /// ```
     #[inline]
-    pub fn read_i16_into(&mut self, dst: &mut [i16]) -> IoResult<()> {
-        self.endianness.read_i16_into(self.inner.by_ref(), dst)
+    pub fn read_i16_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[i16]> + ?Sized,
+    {
+        self.endianness
+            .read_i16_into(self.inner.by_ref(), dst.as_mut())
     }
 
     /// Reads an unsigned 16 bit integer from the underlying reader.
@@ -428,7 +880,9 @@ where
 
     /// Reads a sequence of unsigned 16 bit integers from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [u16]`, `&mut Vec<u16>` or `&mut Box<[u16]>`.
+    /// It is either filled completely or an error is returned.
     /// If an error is returned,
     /// the contents of `dst` are unspecified.
     ///
@@ -438,8 +892,12 @@ where
     ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_u16_into(&mut self, dst: &mut [u16]) -> IoResult<()> {
-        self.endianness.read_u16_into(self.inner.by_ref(), dst)
+    pub fn read_u16_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[u16]> + ?Sized,
+    {
+        self.endianness
+            .read_u16_into(self.inner.by_ref(), dst.as_mut())
     }
 
     /// Reads a signed 32 bit integer from the underlying reader.
@@ -456,7 +914,9 @@ where
 
     /// Reads a sequence of signed 32 bit integers from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [i32]`, `&mut Vec<i32>` or `&mut Box<[i32]>`.
+    /// It is either filled completely or an error is returned.
     /// If an error is returned,
     /// the contents of `dst` are unspecified.
     ///
@@ -466,8 +926,12 @@ where
     ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_i32_into(&mut self, dst: &mut [i32]) -> IoResult<()> {
-        self.endianness.read_i32_into(self.inner.by_ref(), dst)
+    pub fn read_i32_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[i32]> + ?Sized,
+    {
+        self.endianness
+            .read_i32_into(self.inner.by_ref(), dst.as_mut())
     }
 
     /// Reads an unsigned 32 bit integer from the underlying reader.
@@ -484,7 +948,9 @@ where
 
     /// Reads a sequence of unsigned 32 bit integers from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [u32]`, `&mut Vec<u32>` or `&mut Box<[u32]>`.
+    /// It is either filled completely or an error is returned.
     /// If an error is returned,
     /// the contents of `dst` are unspecified.
     ///
@@ -494,8 +960,91 @@ where
     ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_u32_into(&mut self, dst: &mut [u32]) -> IoResult<()> {
-        self.endianness.read_u32_into(self.inner.by_ref(), dst)
+    pub fn read_u32_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[u32]> + ?Sized,
+    {
+        self.endianness
+            .read_u32_into(self.inner.by_ref(), dst.as_mut())
+    }
+
+    /// Reads a sequence of unsigned 32 bit integers from the underlying
+    /// reader, starting at index `start` of `dst`,
+    /// and returns the index of the next element to be filled.
+    ///
+    /// Unlike [`read_u32_into`], which is all-or-nothing,
+    /// this method stops early without an error
+    /// if the reader reports [`ErrorKind::WouldBlock`].
+    /// The elements read so far are kept in `dst`,
+    /// and the bytes of an element which was only partially read
+    /// are kept in `state`,
+    /// so that the operation can be resumed later
+    /// by passing the returned index as `start`
+    /// together with the same `state`.
+    /// The buffer is full once the returned index is equal to `dst.len()`.
+    ///
+    /// # Errors
+    ///
+    /// Any other I/O error is returned as is,
+    /// with the exception of [`ErrorKind::Interrupted`],
+    /// which is retried.
+    /// Reaching the end of the stream results in an error of kind
+    /// [`ErrorKind::UnexpectedEof`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is greater than `dst.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, ResumeState};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x01, 0x00][..]);
+    /// let mut state = ResumeState::default();
+    /// let mut words = [0; 2];
+    /// let mut filled = 0;
+    /// while filled < words.len() {
+    ///     filled = rdr.read_u32_into_resumable(&mut words, filled, &mut state)?;
+    /// }
+    /// assert_eq!(words, [7, 256]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`read_u32_into`]: #method.read_u32_into
+    /// [`ErrorKind::WouldBlock`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.WouldBlock
+    /// [`ErrorKind::Interrupted`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.Interrupted
+    /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+    pub fn read_u32_into_resumable(
+        &mut self,
+        dst: &mut [u32],
+        start: usize,
+        state: &mut ResumeState,
+    ) -> IoResult<usize> {
+        for (i, e) in dst[start..].iter_mut().enumerate() {
+            while state.len < state.buf.len() {
+                match self.inner.read(&mut state.buf[state.len..]) {
+                    Ok(0) => {
+                        return Err(IoError::new(
+                            ErrorKind::UnexpectedEof,
+                            "failed to fill whole buffer",
+                        ))
+                    }
+                    Ok(n) => state.len += n,
+                    Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                    Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
+                        return Ok(start + i);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            *e = self.endianness.read_u32(&state.buf[..])?;
+            state.len = 0;
+        }
+        Ok(dst.len())
     }
 
     /// Reads a signed 64 bit integer from the underlying reader.
@@ -512,7 +1061,9 @@ where
 
     /// Reads a sequence of signed 64 bit integers from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [i64]`, `&mut Vec<i64>` or `&mut Box<[i64]>`.
+    /// It is either filled completely or an error is returned.
     /// If an error is returned,
     /// the contents of `dst` are unspecified.
     ///
@@ -522,8 +1073,12 @@ where
     ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_i64_into(&mut self, dst: &mut [i64]) -> IoResult<()> {
-        self.endianness.read_i64_into(self.inner.by_ref(), dst)
+    pub fn read_i64_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[i64]> + ?Sized,
+    {
+        self.endianness
+            .read_i64_into(self.inner.by_ref(), dst.as_mut())
     }
 
     /// Reads an unsigned 16 bit integer from the underlying reader.
@@ -540,7 +1095,9 @@ where
 
     /// Reads a sequence of unsigned 64 bit integers from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [u64]`, `&mut Vec<u64>` or `&mut Box<[u64]>`.
+    /// It is either filled completely or an error is returned.
     /// If an error is returned,
     /// the contents of `dst` are unspecified.
     ///
@@ -550,8 +1107,12 @@ where
     ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_u64_into(&mut self, dst: &mut [u64]) -> IoResult<()> {
-        self.endianness.read_u64_into(self.inner.by_ref(), dst)
+    pub fn read_u64_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[u64]> + ?Sized,
+    {
+        self.endianness
+            .read_u64_into(self.inner.by_ref(), dst.as_mut())
     }
 
     /// Reads a signed 128 bit integer from the underlying reader.
@@ -568,7 +1129,9 @@ where
 
     /// Reads a sequence of signed 128 bit integers from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [i128]`, `&mut Vec<i128>` or `&mut Box<[i128]>`.
+    /// It is either filled completely or an error is returned.
     /// If an error is returned,
     /// the contents of `dst` are unspecified.
     ///
@@ -578,8 +1141,12 @@ where
     ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_i128_into(&mut self, dst: &mut [i128]) -> IoResult<()> {
-        self.endianness.read_i128_into(self.inner.by_ref(), dst)
+    pub fn read_i128_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[i128]> + ?Sized,
+    {
+        self.endianness
+            .read_i128_into(self.inner.by_ref(), dst.as_mut())
     }
 
     /// Reads an unsigned 16 bit integer from the underlying reader.
@@ -596,7 +1163,9 @@ where
 
     /// Reads a sequence of unsigned 128 bit integers from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [u128]`, `&mut Vec<u128>` or `&mut Box<[u128]>`.
+    /// It is either filled completely or an error is returned.
     /// If an error is returned,
     /// the contents of `dst` are unspecified.
     ///
@@ -606,379 +1175,6239 @@ where
     ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_u128_into(&mut self, dst: &mut [u128]) -> IoResult<()> {
-        self.endianness.read_u128_into(self.inner.by_ref(), dst)
+    pub fn read_u128_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[u128]> + ?Sized,
+    {
+        self.endianness
+            .read_u128_into(self.inner.by_ref(), dst.as_mut())
     }
 
-    /// Reads a IEEE754 single-precision (4 bytes) floating point number from
-    /// the underlying reader.
+    /// Reads a signed integer of `nbytes` bytes from the underlying reader,
+    /// sign-extended to 64 bits.
+    ///
+    /// This suits formats where the width of a field
+    /// is only known at run time.
     ///
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
     ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 8,
+    /// as in `byteorder`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0xFE, 0xFF, 0xFF, 0x7F, 0x00][..]);
+    /// assert_eq!(rdr.read_int(3)?, -2);
+    /// assert_eq!(rdr.read_int(2)?, 0x7F);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_f32(&mut self) -> IoResult<f32> {
-        self.endianness.read_f32(self.inner.by_ref())
+    pub fn read_int(&mut self, nbytes: usize) -> IoResult<i64> {
+        self.endianness.read_int(self.inner.by_ref(), nbytes)
     }
 
-    /// Reads a sequence of IEEE754 single-precision (4 bytes) floating point numbers
-    /// from the underlying reader.
+    /// Reads an unsigned integer of `nbytes` bytes from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
-    /// If an error is returned,
-    /// the contents of `dst` are unspecified.
+    /// This suits formats where the width of a field
+    /// is only known at run time.
     ///
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
     ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 8,
+    /// as in `byteorder`.
+    ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_f32_into(&mut self, dst: &mut [f32]) -> IoResult<()> {
-        self.endianness.read_f32_into(self.inner.by_ref(), dst)
+    pub fn read_uint(&mut self, nbytes: usize) -> IoResult<u64> {
+        self.endianness.read_uint(self.inner.by_ref(), nbytes)
     }
 
-    /// Reads a IEEE754 double-precision (8 bytes) floating point number from
-    /// the underlying reader.
+    /// Reads a signed integer of `nbytes` bytes from the underlying reader,
+    /// sign-extended to 128 bits.
     ///
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
     ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 16,
+    /// as in `byteorder`.
+    ///
     /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn read_f64(&mut self) -> IoResult<f64> {
-        self.endianness.read_f64(self.inner.by_ref())
+    pub fn read_int128(&mut self, nbytes: usize) -> IoResult<i128> {
+        self.endianness.read_int128(self.inner.by_ref(), nbytes)
     }
 
-    /// Reads a sequence of IEEE754 double-precision (8 bytes) floating point numbers
-    /// from the underlying reader.
+    /// Reads an unsigned integer of `nbytes` bytes from the underlying reader.
     ///
-    /// The given buffer is either filled completely or an error is returned.
-    /// If an error is returned,
-    /// the contents of `dst` are unspecified.
+    /// This suits fields such as identifiers and big decimals
+    /// whose stored width varies.
     ///
     /// # Errors
     ///
     /// This method returns the same errors as [`Read::read_exact`].
     ///
-    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
-    #[inline]
-    pub fn read_f64_into(&mut self, dst: &mut [f64]) -> IoResult<()> {
-        self.endianness.read_f64_into(self.inner.by_ref(), dst)
-    }
-}
-
-impl<W, E> ByteOrdered<W, E>
-where
-    W: WriteBytesExt,
-    E: Endian,
-{
-    /// Writes a signed 8 bit integer to the underlying writer.
-    ///
-    /// Note that since this writes a single byte, no byte order conversions
-    /// are used. It is included for completeness.
-    ///
-    /// # Errors
-    ///
-    /// This method returns the same errors as [`Write::write_all`].
-    ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
-    #[inline]
-    pub fn write_i8(&mut self, x: i8) -> IoResult<()> {
-        self.inner.write_i8(x)
-    }
-
-    /// Writes an unsigned 8 bit integer to the underlying writer.
-    ///
-    /// Note that since this writes a single byte, no byte order conversions
-    /// are used. It is included for completeness.
-    ///
-    /// # Errors
-    ///
-    /// This method returns the same errors as [`Write::write_all`].
-    ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
-    #[inline]
-    pub fn write_u8(&mut self, x: u8) -> IoResult<()> {
-        self.inner.write_u8(x)
-    }
-
-    /// Writes a signed 16 bit integer to the underlying writer.
+    /// # Panics
     ///
-    /// # Errors
-    ///
-    /// This method returns the same errors as [`Write::write_all`].
-    ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// Panics if `nbytes` is not between 1 and 16,
+    /// as in `byteorder`.
     ///
     /// # Examples
     ///
-    /// Write signed 16 bit big-endian integers to a `Write`:
-    ///
     /// ```rust
     /// use byteordered::ByteOrdered;
     ///
-    /// let mut wtr = ByteOrdered::be(Vec::new());
-    /// wtr.write_i16(193).unwrap();
-    /// wtr.write_i16(-132).unwrap();
-    /// assert_eq!(wtr.into_inner(), b"\x00\xc1\xff\x7c");
+    /// # fn run() -> std::io::Result<()> {
+    /// let data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A];
+    /// let mut rdr = ByteOrdered::be(&data[..]);
+    /// assert_eq!(rdr.read_uint128(10)?, 0x0102_0304_0506_0708_090A);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
     /// ```
-    #[inline]
-    pub fn write_i16(&mut self, x: i16) -> IoResult<()> {
-        self.endianness.write_i16(self.inner.by_ref(), x)
-    }
-
-    /// Writes an unsigned 16 bit integer to the underlying writer.
-    ///
-    /// # Errors
-    ///
-    /// This method returns the same errors as [`Write::write_all`].
     ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn write_u16(&mut self, x: u16) -> IoResult<()> {
-        self.endianness.write_u16(self.inner.by_ref(), x)
+    pub fn read_uint128(&mut self, nbytes: usize) -> IoResult<u128> {
+        self.endianness.read_uint128(self.inner.by_ref(), nbytes)
     }
 
-    /// Writes a signed 32 bit integer to the underlying writer.
+    /// Reads a IEEE754 single-precision (4 bytes) floating point number from
+    /// the underlying reader.
     ///
     /// # Errors
     ///
-    /// This method returns the same errors as [`Write::write_all`].
+    /// This method returns the same errors as [`Read::read_exact`].
     ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn write_i32(&mut self, x: i32) -> IoResult<()> {
-        self.endianness.write_i32(self.inner.by_ref(), x)
+    pub fn read_f32(&mut self) -> IoResult<f32> {
+        self.endianness.read_f32(self.inner.by_ref())
     }
 
-    /// Writes an unsigned 32 bit integer to the underlying writer.
-    ///
-    /// # Errors
-    ///
-    /// This method returns the same errors as [`Write::write_all`].
+    /// Reads a sequence of IEEE754 single-precision (4 bytes) floating point numbers
+    /// from the underlying reader.
     ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
-    #[inline]
-    pub fn write_u32(&mut self, x: u32) -> IoResult<()> {
-        self.endianness.write_u32(self.inner.by_ref(), x)
-    }
-
-    /// Writes a signed 64 bit integer to the underlying writer.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [f32]`, `&mut Vec<f32>` or `&mut Box<[f32]>`.
+    /// It is either filled completely or an error is returned.
+    /// If an error is returned,
+    /// the contents of `dst` are unspecified.
     ///
     /// # Errors
     ///
-    /// This method returns the same errors as [`Write::write_all`].
+    /// This method returns the same errors as [`Read::read_exact`].
     ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn write_i64(&mut self, x: i64) -> IoResult<()> {
-        self.endianness.write_i64(self.inner.by_ref(), x)
+    pub fn read_f32_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[f32]> + ?Sized,
+    {
+        self.endianness
+            .read_f32_into(self.inner.by_ref(), dst.as_mut())
     }
 
-    /// Writes an unsigned 64 bit integer to the underlying writer.
+    /// Reads a IEEE754 double-precision (8 bytes) floating point number from
+    /// the underlying reader.
     ///
     /// # Errors
     ///
-    /// This method returns the same errors as [`Write::write_all`].
+    /// This method returns the same errors as [`Read::read_exact`].
     ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn write_u64(&mut self, x: u64) -> IoResult<()> {
-        self.endianness.write_u64(self.inner.by_ref(), x)
+    pub fn read_f64(&mut self) -> IoResult<f64> {
+        self.endianness.read_f64(self.inner.by_ref())
     }
 
-    /// Writes a signed 128 bit integer to the underlying writer.
-    ///
-    /// # Errors
-    ///
-    /// This method returns the same errors as [`Write::write_all`].
+    /// Reads a sequence of IEEE754 double-precision (8 bytes) floating point numbers
+    /// from the underlying reader.
     ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
-    #[inline]
-    pub fn write_i128(&mut self, x: i128) -> IoResult<()> {
-        self.endianness.write_i128(self.inner.by_ref(), x)
-    }
-
-    /// Writes an unsigned 128 bit integer to the underlying writer.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [f64]`, `&mut Vec<f64>` or `&mut Box<[f64]>`.
+    /// It is either filled completely or an error is returned.
+    /// If an error is returned,
+    /// the contents of `dst` are unspecified.
     ///
     /// # Errors
     ///
-    /// This method returns the same errors as [`Write::write_all`].
+    /// This method returns the same errors as [`Read::read_exact`].
     ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
     #[inline]
-    pub fn write_u128(&mut self, x: u128) -> IoResult<()> {
-        self.endianness.write_u128(self.inner.by_ref(), x)
+    pub fn read_f64_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[f64]> + ?Sized,
+    {
+        self.endianness
+            .read_f64_into(self.inner.by_ref(), dst.as_mut())
     }
 
-    /// Writes a IEEE754 single-precision (4 bytes) floating point number to
-    /// the underlying writer.
-    ///
-    /// # Errors
-    ///
-    /// This method returns the same errors as [`Write::write_all`].
-    ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
-    #[inline]
-    pub fn write_f32(&mut self, x: f32) -> IoResult<()> {
-        self.endianness.write_f32(self.inner.by_ref(), x)
-    }
+    fn_read_into_uninit!(
+        /// Reads a sequence of signed 16 bit integers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i16_into_uninit,
+        i16
+    );
 
-    /// Writes a IEEE754 double-precision (8 bytes) floating point number to
-    /// the underlying writer.
-    ///
-    /// # Errors
-    ///
-    /// This method returns the same errors as [`Write::write_all`].
-    ///
-    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
-    #[inline]
-    pub fn write_f64(&mut self, x: f64) -> IoResult<()> {
-        self.endianness.write_f64(self.inner.by_ref(), x)
-    }
+    fn_read_into_uninit!(
+        /// Reads a sequence of unsigned 16 bit integers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        /// use std::mem::MaybeUninit;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let mut buf = [MaybeUninit::<u16>::uninit(); 2];
+        /// let mut rdr = ByteOrdered::be(&[0x00, 0xc1, 0xff, 0x7c][..]);
+        /// let values = rdr.read_u16_into_uninit(&mut buf)?;
+        /// assert_eq!(values, [0x00c1, 0xff7c]);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        read_u16_into_uninit,
+        u16
+    );
+
+    fn_read_into_uninit!(
+        /// Reads a sequence of signed 32 bit integers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i32_into_uninit,
+        i32
+    );
+
+    fn_read_into_uninit!(
+        /// Reads a sequence of unsigned 32 bit integers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u32_into_uninit,
+        u32
+    );
+
+    fn_read_into_uninit!(
+        /// Reads a sequence of signed 64 bit integers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i64_into_uninit,
+        i64
+    );
+
+    fn_read_into_uninit!(
+        /// Reads a sequence of unsigned 64 bit integers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u64_into_uninit,
+        u64
+    );
+
+    fn_read_into_uninit!(
+        /// Reads a sequence of signed 128 bit integers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i128_into_uninit,
+        i128
+    );
+
+    fn_read_into_uninit!(
+        /// Reads a sequence of unsigned 128 bit integers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u128_into_uninit,
+        u128
+    );
+
+    fn_read_into_uninit!(
+        /// Reads a sequence of IEEE754 single-precision (4 bytes) floating point numbers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f32_into_uninit,
+        f32
+    );
+
+    fn_read_into_uninit!(
+        /// Reads a sequence of IEEE754 double-precision (8 bytes) floating point numbers from the underlying reader
+        /// into a buffer of possibly uninitialized memory.
+        ///
+        /// On success, the whole buffer is initialized
+        /// and returned as a slice of initialized values.
+        /// If an error is returned,
+        /// the contents of `dst` are unspecified.
+        ///
+        /// With the `read_buf` feature,
+        /// the bytes are read directly into `dst`.
+        /// Otherwise, `dst` is zeroed before reading,
+        /// since `Read::read_exact` must not be given uninitialized memory.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f64_into_uninit,
+        f64
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` signed 16 bit integers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i16_vec,
+        read_i16_into,
+        i16
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` unsigned 16 bit integers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u16_vec,
+        read_u16_into,
+        u16
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` signed 32 bit integers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i32_vec,
+        read_i32_into,
+        i32
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` unsigned 32 bit integers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let mut rdr = ByteOrdered::le(&[0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00][..]);
+        /// assert_eq!(rdr.read_u32_vec(2)?, vec![2, 7]);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u32_vec,
+        read_u32_into,
+        u32
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` signed 64 bit integers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i64_vec,
+        read_i64_into,
+        i64
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` unsigned 64 bit integers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u64_vec,
+        read_u64_into,
+        u64
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` signed 128 bit integers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i128_vec,
+        read_i128_into,
+        i128
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` unsigned 128 bit integers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u128_vec,
+        read_u128_into,
+        u128
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` IEEE754 single-precision (4 bytes) floating point numbers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f32_vec,
+        read_f32_into,
+        f32
+    );
+
+    fn_read_vec!(
+        /// Reads a sequence of `count` IEEE754 double-precision (8 bytes) floating point numbers from the underlying reader
+        /// into a newly allocated vector of exactly that length.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// The partially read vector is discarded on error.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f64_vec,
+        read_f64_into,
+        f64
+    );
 }
 
-impl<T, E> BufRead for ByteOrdered<T, E>
-where
-    T: BufRead,
-{
-    #[inline]
-    fn fill_buf(&mut self) -> IoResult<&[u8]> {
-        self.inner.fill_buf()
-    }
+/// The width of an unsigned integer field holding a length or a tag,
+/// for use with [`ByteOrdered::read_len_usize`],
+/// [`ByteOrdered::read_count`] and [`ByteOrdered::tagged`].
+/// It also gives the width of the values sampled by [`score_endianness`].
+///
+/// [`score_endianness`]: fn.score_endianness.html
+/// [`ByteOrdered::read_len_usize`]: struct.ByteOrdered.html#method.read_len_usize
+/// [`ByteOrdered::read_count`]: struct.ByteOrdered.html#method.read_count
+/// [`ByteOrdered::tagged`]: struct.ByteOrdered.html#method.tagged
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LenWidth {
+    /// An unsigned 8 bit integer.
+    U8,
+    /// An unsigned 16 bit integer.
+    U16,
+    /// An unsigned 32 bit integer.
+    U32,
+    /// An unsigned 64 bit integer.
+    U64,
+}
 
-    #[inline]
-    fn consume(&mut self, amt: usize) {
-        self.inner.consume(amt)
-    }
+/// The byte width and byte order of an unsigned integer,
+/// for use with [`ByteOrdered::read_number`]
+/// and [`ByteOrdered::write_number`].
+///
+/// Flexible formats often declare both properties in a header,
+/// so that they travel together from then on.
+///
+/// [`ByteOrdered::read_number`]: struct.ByteOrdered.html#method.read_number
+/// [`ByteOrdered::write_number`]: struct.ByteOrdered.html#method.write_number
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    /// The number of bytes of each value, from 1 to 8.
+    pub width: u8,
+    /// The byte order of each value.
+    pub endianness: Endianness,
+}
 
+impl NumberFormat {
+    /// Creates a number format with the given width and byte order.
     #[inline]
-    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> IoResult<usize> {
-        self.inner.read_until(byte, buf)
+    pub fn new(width: u8, endianness: Endianness) -> Self {
+        NumberFormat { width, endianness }
     }
 
-    #[inline]
-    fn read_line(&mut self, buf: &mut String) -> IoResult<usize> {
-        self.inner.read_line(buf)
+    /// Checks that the width is supported,
+    /// returning it as a number of bytes.
+    fn checked_width(self) -> IoResult<usize> {
+        match self.width {
+            1..=8 => Ok(usize::from(self.width)),
+            w => Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("unsupported number width {}", w),
+            )),
+        }
     }
 }
 
-impl<T, E> Seek for ByteOrdered<T, E>
-where
-    T: Seek,
-{
-    #[inline]
-    fn seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
-        self.inner.seek(pos)
-    }
+/// Assembles an unsigned 24 bit integer from its three bytes.
+#[inline]
+fn u24_from_bytes(bytes: &[u8], big: bool) -> u32 {
+    let (hi, lo) = if big {
+        (bytes[0], bytes[2])
+    } else {
+        (bytes[2], bytes[0])
+    };
+    u32::from(hi) << 16 | u32::from(bytes[1]) << 8 | u32::from(lo)
 }
 
-#[cfg(test)]
-mod tests {
-    // TODO test moar
-    use super::ByteOrdered;
-    use base::Endianness;
-    static TEST_BYTES: &'static [u8] = &[0x12, 0x34, 0x56, 0x78, 0x21, 0x43, 0x65, 0x87];
-
-    static TEST_U64DATA_LE: &'static [u64] = &[0x87654321_78563412];
-    static TEST_U64DATA_BE: &'static [u64] = &[0x12345678_21436587];
+/// Splits an unsigned 24 bit integer into its three bytes.
+#[inline]
+fn u24_to_bytes(x: u32, big: bool) -> [u8; 3] {
+    let bytes = [(x >> 16) as u8, (x >> 8) as u8, x as u8];
+    if big {
+        bytes
+    } else {
+        [bytes[2], bytes[1], bytes[0]]
+    }
+}
 
-    #[test]
-    fn test_read_u64() {
-        let mut data = TEST_BYTES;
-        let mut reader = ByteOrdered::le(&mut data);
-        let words = [reader.read_u64().unwrap()];
-        assert_eq!(words, TEST_U64DATA_LE);
+/// Sign-extends a 24 bit integer to 32 bits.
+#[inline]
+fn i24_from_u24(x: u32) -> i32 {
+    (x << 8) as i32 >> 8
+}
 
-        let mut data = TEST_BYTES;
-        let mut reader = ByteOrdered::be(&mut data);
-        let words = [reader.read_u64().unwrap()];
-        assert_eq!(words, TEST_U64DATA_BE);
+/// Converts a length read from a stream into the given target type,
+/// failing if it does not fit.
+fn checked_len<T>(len: u64) -> IoResult<T>
+where
+    T: TryFrom<u64>,
+{
+    T::try_from(len).map_err(|_| {
+        IoError::new(
+            ErrorKind::InvalidData,
+            format!("length {} does not fit in the target's address space", len),
+        )
+    })
+}
 
-        let mut data = TEST_BYTES;
-        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Little);
-        let words = [reader.read_u64().unwrap()];
-        assert_eq!(words, TEST_U64DATA_LE);
+/// Private macro for reading a primitive value
+/// and converting it into another type.
+macro_rules! fn_read_as {
+    ($(#[$attr:meta])* $method:ident, $read:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method<T>(&mut self) -> IoResult<T>
+        where
+            T: From<$ty>,
+        {
+            self.$read().map(T::from)
+        }
+    };
+}
 
-        let mut data = TEST_BYTES;
-        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Big);
-        let words = [reader.read_u64().unwrap()];
-        assert_eq!(words, TEST_U64DATA_BE);
-    }
+/// Private macro for reading a primitive value
+/// and applying a transformation to it.
+macro_rules! fn_read_map {
+    ($(#[$attr:meta])* $method:ident, $read:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method<T, F>(&mut self, f: F) -> IoResult<T>
+        where
+            F: FnOnce($ty) -> T,
+        {
+            self.$read().map(f)
+        }
+    };
+}
 
-    #[test]
-    fn test_write_u64() {
-        let mut writer = ByteOrdered::le(Vec::new());
-        for v in TEST_U64DATA_LE {
-            writer.write_u64(*v).unwrap();
+/// Private macro for reading an unsigned integer
+/// which is absent if it equals a sentinel value.
+macro_rules! fn_read_opt_sentinel {
+    ($(#[$attr:meta])* $method:ident, $read:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method(&mut self, sentinel: $ty) -> IoResult<Option<$ty>> {
+            let value = self.$read()?;
+            Ok(if value == sentinel { None } else { Some(value) })
         }
-        assert_eq!(&*writer.into_inner(), TEST_BYTES);
+    };
+}
 
-        let mut writer = ByteOrdered::be(Vec::new());
-        for v in TEST_U64DATA_BE {
-            writer.write_u64(*v).unwrap();
+/// Private macro for reading a primitive value
+/// which is preceded by a presence flag.
+macro_rules! fn_read_optional {
+    ($(#[$attr:meta])* $method:ident, $read:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method(&mut self) -> IoResult<Option<$ty>> {
+            self.read_optional_with(|rdr| rdr.$read())
         }
-        assert_eq!(&*writer.into_inner(), TEST_BYTES);
+    };
+}
 
-        let mut writer = ByteOrdered::runtime(Vec::new(), Endianness::Little);
-        for v in TEST_U64DATA_LE {
-            writer.write_u64(*v).unwrap();
+/// Private macro for reading a primitive value
+/// and checking it against an expected one.
+macro_rules! fn_expect {
+    ($(#[$attr:meta])* $method:ident, $read:ident, $ty:ty) => {
+        $(#[$attr])*
+        pub fn $method(&mut self, expected: $ty) -> IoResult<()> {
+            let found = self.$read()?;
+            if found == expected {
+                Ok(())
+            } else {
+                Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "unexpected {}: expected {:#x}, found {:#x}",
+                        stringify!($ty),
+                        expected,
+                        found
+                    ),
+                ))
+            }
         }
-        assert_eq!(&*writer.into_inner(), TEST_BYTES);
+    };
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: ReadBytesExt,
+    E: Endian,
+{
+    /// Creates an iterator which decodes exactly `count` records
+    /// with the given function.
+    ///
+    /// This suits formats with an explicit record count in their header.
+    /// The function reads through this wrapper,
+    /// so the assumed byte order applies.
+    /// If decoding a record fails,
+    /// the error is yielded and the iteration stops,
+    /// so fewer than `count` items may be produced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x00, 0x02, 0x00, 0x0A, 0x00, 0x0B][..]);
+    /// let count = rdr.read_u16()? as usize;
+    /// let records = rdr
+    ///     .take_records(count, |rdr| rdr.read_u16())
+    ///     .collect::<std::io::Result<Vec<_>>>()?;
+    /// assert_eq!(records, vec![10, 11]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn take_records<'a, T, F>(
+        &'a mut self,
+        count: usize,
+        mut decode: F,
+    ) -> impl Iterator<Item = IoResult<T>> + 'a
+    where
+        F: FnMut(&mut Self) -> IoResult<T> + 'a,
+    {
+        let mut remaining = count;
+        iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            let record = decode(self);
+            remaining = if record.is_ok() { remaining - 1 } else { 0 };
+            Some(record)
+        })
+    }
+
+    fn_read_as!(
+        /// Reads an unsigned 8 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u8_as,
+        read_u8,
+        u8
+    );
+
+    fn_read_as!(
+        /// Reads a signed 8 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i8_as,
+        read_i8,
+        i8
+    );
+
+    fn_read_as!(
+        /// Reads an unsigned 16 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u16_as,
+        read_u16,
+        u16
+    );
+
+    fn_read_as!(
+        /// Reads a signed 16 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i16_as,
+        read_i16,
+        i16
+    );
+
+    fn_read_as!(
+        /// Reads an unsigned 32 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// This keeps strong typing at the parsing boundary,
+        /// by reading straight into a newtype
+        /// which implements `From<u32>`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// #[derive(Debug, PartialEq)]
+        /// struct Offset(u32);
+        ///
+        /// impl From<u32> for Offset {
+        ///     fn from(v: u32) -> Self {
+        ///         Offset(v)
+        ///     }
+        /// }
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let mut rdr = ByteOrdered::be(&[0x00, 0x00, 0x01, 0x00][..]);
+        /// let offset: Offset = rdr.read_u32_as()?;
+        /// assert_eq!(offset, Offset(256));
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u32_as,
+        read_u32,
+        u32
+    );
+
+    fn_read_as!(
+        /// Reads a signed 32 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i32_as,
+        read_i32,
+        i32
+    );
+
+    fn_read_as!(
+        /// Reads an unsigned 64 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u64_as,
+        read_u64,
+        u64
+    );
+
+    fn_read_as!(
+        /// Reads a signed 64 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i64_as,
+        read_i64,
+        i64
+    );
+
+    fn_read_as!(
+        /// Reads an unsigned 128 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u128_as,
+        read_u128,
+        u128
+    );
+
+    fn_read_as!(
+        /// Reads a signed 128 bit integer from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i128_as,
+        read_i128,
+        i128
+    );
+
+    fn_read_as!(
+        /// Reads a IEEE754 single-precision (4 bytes) floating point number from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f32_as,
+        read_f32,
+        f32
+    );
+
+    fn_read_as!(
+        /// Reads a IEEE754 double-precision (8 bytes) floating point number from the underlying reader
+        /// and converts it into `T`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f64_as,
+        read_f64,
+        f64
+    );
+
+    fn_read_map!(
+        /// Reads an unsigned 8 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u8_map,
+        read_u8,
+        u8
+    );
+
+    fn_read_map!(
+        /// Reads a signed 8 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i8_map,
+        read_i8,
+        i8
+    );
+
+    fn_read_map!(
+        /// Reads an unsigned 16 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u16_map,
+        read_u16,
+        u16
+    );
+
+    fn_read_map!(
+        /// Reads a signed 16 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i16_map,
+        read_i16,
+        i16
+    );
+
+    fn_read_map!(
+        /// Reads an unsigned 32 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// // an offset stored in units of 4 bytes
+        /// let mut rdr = ByteOrdered::be(&[0x00, 0x00, 0x01, 0x00][..]);
+        /// let offset = rdr.read_u32_map(|v| u64::from(v) * 4)?;
+        /// assert_eq!(offset, 1024);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u32_map,
+        read_u32,
+        u32
+    );
+
+    fn_read_map!(
+        /// Reads a signed 32 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i32_map,
+        read_i32,
+        i32
+    );
+
+    fn_read_map!(
+        /// Reads an unsigned 64 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u64_map,
+        read_u64,
+        u64
+    );
+
+    fn_read_map!(
+        /// Reads a signed 64 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i64_map,
+        read_i64,
+        i64
+    );
+
+    fn_read_map!(
+        /// Reads an unsigned 128 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u128_map,
+        read_u128,
+        u128
+    );
+
+    fn_read_map!(
+        /// Reads a signed 128 bit integer from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i128_map,
+        read_i128,
+        i128
+    );
+
+    fn_read_map!(
+        /// Reads a IEEE754 single-precision (4 bytes) floating point number from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f32_map,
+        read_f32,
+        f32
+    );
+
+    fn_read_map!(
+        /// Reads a IEEE754 double-precision (8 bytes) floating point number from the underlying reader
+        /// and applies the given function to it.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f64_map,
+        read_f64,
+        f64
+    );
+
+    fn_read_opt_sentinel!(
+        /// Reads an unsigned 16 bit integer from the underlying reader,
+        /// returning `None` if it equals the given sentinel value.
+        ///
+        /// This captures the common idiom of a reserved value,
+        /// such as all ones, meaning that the field is absent.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u16_opt_sentinel,
+        read_u16,
+        u16
+    );
+
+    fn_read_opt_sentinel!(
+        /// Reads an unsigned 32 bit integer from the underlying reader,
+        /// returning `None` if it equals the given sentinel value.
+        ///
+        /// This captures the common idiom of a reserved value,
+        /// such as all ones, meaning that the field is absent.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let mut rdr = ByteOrdered::le(&[0x2A, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF][..]);
+        /// assert_eq!(rdr.read_u32_opt_sentinel(u32::MAX)?, Some(42));
+        /// assert_eq!(rdr.read_u32_opt_sentinel(u32::MAX)?, None);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u32_opt_sentinel,
+        read_u32,
+        u32
+    );
+
+    fn_read_opt_sentinel!(
+        /// Reads an unsigned 64 bit integer from the underlying reader,
+        /// returning `None` if it equals the given sentinel value.
+        ///
+        /// This captures the common idiom of a reserved value,
+        /// such as all ones, meaning that the field is absent.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u64_opt_sentinel,
+        read_u64,
+        u64
+    );
+
+    /// Reads a presence flag from the underlying reader,
+    /// then decodes a value with the given function if the flag is set.
+    ///
+    /// The flag is a single byte,
+    /// where zero means that the value is absent
+    /// and any other value means that it follows.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`]
+    /// while reading the flag,
+    /// and any error produced by `decode`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0x01, 0x02, 0x00, 0x03, 0x00, 0x00][..]);
+    /// let point = rdr.read_optional_with(|rdr| Ok((rdr.read_u16()?, rdr.read_u16()?)))?;
+    /// assert_eq!(point, Some((2, 3)));
+    /// assert_eq!(rdr.read_optional_with(|rdr| rdr.read_u16())?, None);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_optional_with<T, F>(&mut self, decode: F) -> IoResult<Option<T>>
+    where
+        F: FnOnce(&mut Self) -> IoResult<T>,
+    {
+        if self.read_u8()? == 0 {
+            Ok(None)
+        } else {
+            decode(self).map(Some)
+        }
+    }
+
+    fn_read_optional!(
+        /// Reads a presence flag from the underlying reader,
+        /// followed by an unsigned 16 bit integer if the flag is set.
+        ///
+        /// See [`read_optional_with`] for the encoding of the flag.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`read_optional_with`]: #method.read_optional_with
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_optional_u16,
+        read_u16,
+        u16
+    );
+
+    fn_read_optional!(
+        /// Reads a presence flag from the underlying reader,
+        /// followed by an unsigned 32 bit integer if the flag is set.
+        ///
+        /// See [`read_optional_with`] for the encoding of the flag.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let mut rdr = ByteOrdered::be(&[0x01, 0x00, 0x00, 0x00, 0x2A, 0x00][..]);
+        /// assert_eq!(rdr.read_optional_u32()?, Some(42));
+        /// assert_eq!(rdr.read_optional_u32()?, None);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`read_optional_with`]: #method.read_optional_with
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_optional_u32,
+        read_u32,
+        u32
+    );
+
+    fn_read_optional!(
+        /// Reads a presence flag from the underlying reader,
+        /// followed by an unsigned 64 bit integer if the flag is set.
+        ///
+        /// See [`read_optional_with`] for the encoding of the flag.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`read_optional_with`]: #method.read_optional_with
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_optional_u64,
+        read_u64,
+        u64
+    );
+
+    /// Reads a length field of the given width from the underlying reader
+    /// and converts it to a `usize`.
+    ///
+    /// This avoids silent truncation of 64-bit lengths
+    /// on targets where `usize` is narrower.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the length does not fit in a `usize` on the target platform.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, LenWidth};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0x10, 0, 0, 0, 0, 0, 0, 0][..]);
+    /// assert_eq!(rdr.read_len_usize(LenWidth::U64)?, 16);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_len_usize(&mut self, width: LenWidth) -> IoResult<usize> {
+        let len = match width {
+            LenWidth::U8 => u64::from(self.read_u8()?),
+            LenWidth::U16 => u64::from(self.read_u16()?),
+            LenWidth::U32 => u64::from(self.read_u32()?),
+            LenWidth::U64 => self.read_u64()?,
+        };
+        checked_len(len)
+    }
+
+    /// Reads an element count field of the given width
+    /// from the underlying reader,
+    /// checking that it does not exceed the given maximum.
+    ///
+    /// Reading the count through this method
+    /// before looping over the elements
+    /// keeps corrupt or malicious input
+    /// from driving an unbounded number of iterations.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the count does not fit in a `usize` on the target platform,
+    /// or if it is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, LenWidth};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x00, 0x02, 0x00, 0x07, 0x00, 0x09][..]);
+    /// let count = rdr.read_count(LenWidth::U16, 16)?;
+    /// let mut values = Vec::with_capacity(count);
+    /// for _ in 0..count {
+    ///     values.push(rdr.read_u16()?);
+    /// }
+    /// assert_eq!(values, [7, 9]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_count(&mut self, width: LenWidth, max: usize) -> IoResult<usize> {
+        let count = self.read_len_usize(width)?;
+        if count > max {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!("count {} exceeds the maximum of {}", count, max),
+            ));
+        }
+        Ok(count)
+    }
+
+    /// Reads a byte order tag from the underlying reader.
+    ///
+    /// The tag is a single byte holding the stable `u8` representation
+    /// of [`Endianness`]: `0` for little endian and `1` for big endian.
+    /// Formats which declare their own byte order up front
+    /// can read the tag and then [`set_endianness`] for the rest of the data.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the byte is not a valid tag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, Endianness};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::runtime(&[0x01, 0x12, 0x34][..], Endianness::Little);
+    /// let e = rdr.read_endianness_tag()?;
+    /// assert_eq!(e, Endianness::Big);
+    /// rdr.set_endianness(e);
+    /// assert_eq!(rdr.read_u16()?, 0x1234);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Endianness`]: ../base/enum.Endianness.html
+    /// [`set_endianness`]: #method.set_endianness
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_endianness_tag(&mut self) -> IoResult<Endianness> {
+        let tag = self.read_u8()?;
+        Endianness::try_from(tag).map_err(|e| IoError::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Reads an unsigned integer in the given number format
+    /// from the underlying reader.
+    ///
+    /// The byte order of the number format takes precedence
+    /// over the one assumed by this wrapper.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// if the width of the format is not between 1 and 8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, Endianness, NumberFormat};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::native(&[0x00, 0x01, 0x02, 0x00, 0x00, 0x00][..]);
+    /// let format = NumberFormat::new(2, Endianness::Big);
+    /// assert_eq!(rdr.read_number(format)?, 0x0001);
+    /// let format = NumberFormat::new(4, Endianness::Little);
+    /// assert_eq!(rdr.read_number(format)?, 0x0002);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn read_number(&mut self, format: NumberFormat) -> IoResult<u64> {
+        let nbytes = format.checked_width()?;
+        match format.endianness {
+            Endianness::Little => self.inner.read_uint::<LittleEndian>(nbytes),
+            Endianness::Big => self.inner.read_uint::<BigEndian>(nbytes),
+        }
+    }
+
+    /// Reads a IEEE754 double-precision (8 bytes) floating point number
+    /// from the underlying reader,
+    /// in the given byte order rather than the one assumed by this wrapper.
+    ///
+    /// On all mainstream platforms,
+    /// floating point numbers are stored in the same byte order as integers.
+    /// This is an escape hatch for the rare formats
+    /// which store floating point numbers byte-reversed
+    /// relative to their integers.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, Endianness};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let data = [0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F];
+    /// let mut rdr = ByteOrdered::be(&data[..]);
+    /// assert_eq!(rdr.read_u16()?, 2);
+    /// assert_eq!(rdr.read_f64_with(Endianness::Little)?, 1.0);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    pub fn read_f64_with(&mut self, order: Endianness) -> IoResult<f64> {
+        order.read_f64(self.inner.by_ref())
+    }
+
+    /// Reads exactly `n` bytes from the underlying reader
+    /// into a new vector,
+    /// provided that `n` does not exceed `max`.
+    ///
+    /// The limit is checked before anything is allocated or read,
+    /// so that a corrupt or malicious length field
+    /// cannot lead to memory exhaustion.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if `n` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x00, 0x03, b'a', b'b', b'c'][..]);
+    /// let len = rdr.read_u16()? as usize;
+    /// assert_eq!(rdr.read_bytes_capped(len, 1024)?, b"abc");
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_bytes_capped(&mut self, n: usize, max: usize) -> IoResult<Vec<u8>> {
+        if n > max {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!("byte count {} exceeds the limit of {}", n, max),
+            ));
+        }
+        let mut buf = vec![0; n];
+        self.inner.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Reads exactly as many bytes as in `expected`
+    /// from the underlying reader
+    /// and checks that they match.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if the bytes read are not the expected ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&b"RIFF\x04\x00\x00\x00"[..]);
+    /// rdr.expect_bytes(b"RIFF")?;
+    /// assert_eq!(rdr.read_u32()?, 4);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn expect_bytes(&mut self, expected: &[u8]) -> IoResult<()> {
+        let mut found = vec![0; expected.len()];
+        self.inner.read_exact(&mut found)?;
+        if found == expected {
+            Ok(())
+        } else {
+            Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "unexpected bytes: expected {:02x?}, found {:02x?}",
+                    expected, found
+                ),
+            ))
+        }
+    }
+
+    /// Reads a complex number from the underlying reader,
+    /// as a pair of IEEE754 single-precision (4 bytes) floating point numbers
+    /// with the real part first and the imaginary part second.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x3F, 0x80, 0, 0, 0xC0, 0, 0, 0][..]);
+    /// assert_eq!(rdr.read_complex_f32()?, (1., -2.));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_complex_f32(&mut self) -> IoResult<(f32, f32)> {
+        let re = self.read_f32()?;
+        let im = self.read_f32()?;
+        Ok((re, im))
+    }
+
+    /// Reads a sequence of interleaved complex numbers
+    /// from the underlying reader,
+    /// each as a pair of IEEE754 single-precision (4 bytes)
+    /// floating point numbers with the real part first.
+    ///
+    /// The given buffer is either filled completely or an error is returned.
+    /// If an error is returned,
+    /// the contents of `dst` are unspecified.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_complex_f32_into(&mut self, dst: &mut [(f32, f32)]) -> IoResult<()> {
+        for e in dst.iter_mut() {
+            *e = self.read_complex_f32()?;
+        }
+        Ok(())
+    }
+
+    /// Reads an unsigned 128 bit integer from the underlying reader
+    /// as two unsigned 64 bit halves, in the given order.
+    ///
+    /// Each half is read in the assumed byte order,
+    /// but which half comes first is chosen by `low_first`,
+    /// independently of the byte order.
+    /// This differs from [`read_u128`],
+    /// where the halves are ordered like the bytes within them:
+    /// the high half comes first in big endian
+    /// and the low half comes first in little endian.
+    /// Some formats store the low half first even in big endian,
+    /// which is not representable as a plain 128 bit read.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let data = [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2];
+    /// let mut rdr = ByteOrdered::be(&data[..]);
+    /// assert_eq!(rdr.read_u128_halves(true)?, 2 << 64 | 1);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`read_u128`]: #method.read_u128
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_u128_halves(&mut self, low_first: bool) -> IoResult<u128> {
+        let first = u128::from(self.read_u64()?);
+        let second = u128::from(self.read_u64()?);
+        Ok(if low_first {
+            second << 64 | first
+        } else {
+            first << 64 | second
+        })
+    }
+
+    /// Reads an unsigned 24 bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[0x12, 0x34, 0x56][..]);
+    /// assert_eq!(rdr.read_u24()?, 0x12_3456);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    pub fn read_u24(&mut self) -> IoResult<u32> {
+        self.endianness.read_u24(self.inner.by_ref())
+    }
+
+    /// Reads a signed 24 bit integer from the underlying reader,
+    /// sign-extended to 32 bits.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    #[inline]
+    pub fn read_i24(&mut self) -> IoResult<i32> {
+        self.endianness.read_i24(self.inner.by_ref())
+    }
+
+    /// Reads a sequence of unsigned 24 bit integers
+    /// from the underlying reader.
+    ///
+    /// All `3 * dst.len()` bytes are read at once
+    /// before the integers are assembled.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [u32]` or `&mut Vec<u32>`.
+    /// It is either filled completely or an error is returned.
+    /// If an error is returned,
+    /// the contents of `dst` are unspecified.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_u24_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[u32]> + ?Sized,
+    {
+        let dst = dst.as_mut();
+        let mut buf = vec![0; dst.len() * 3];
+        self.inner.read_exact(&mut buf)?;
+        let big = self.is_big_endian();
+        for (x, bytes) in dst.iter_mut().zip(buf.chunks_exact(3)) {
+            *x = u24_from_bytes(bytes, big);
+        }
+        Ok(())
+    }
+
+    /// Reads a sequence of signed 24 bit integers
+    /// from the underlying reader,
+    /// each sign-extended to 32 bits.
+    ///
+    /// This is the usual layout of 24 bit PCM audio samples.
+    /// The buffer can be anything which can be viewed as a mutable slice,
+    /// such as `&mut [i32]` or `&mut Vec<i32>`.
+    /// It is either filled completely or an error is returned.
+    /// If an error is returned,
+    /// the contents of `dst` are unspecified.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0xFF, 0xFF, 0x7F, 0x00, 0x00, 0x80][..]);
+    /// let mut samples = [0; 2];
+    /// rdr.read_i24_into(&mut samples)?;
+    /// assert_eq!(samples, [8_388_607, -8_388_608]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    pub fn read_i24_into<D>(&mut self, dst: &mut D) -> IoResult<()>
+    where
+        D: AsMut<[i32]> + ?Sized,
+    {
+        let dst = dst.as_mut();
+        let mut buf = vec![0; dst.len() * 3];
+        self.inner.read_exact(&mut buf)?;
+        let big = self.is_big_endian();
+        for (x, bytes) in dst.iter_mut().zip(buf.chunks_exact(3)) {
+            *x = i24_from_u24(u24_from_bytes(bytes, big));
+        }
+        Ok(())
+    }
+
+    fn_expect!(
+        /// Reads an unsigned 8 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_u8,
+        read_u8,
+        u8
+    );
+
+    fn_expect!(
+        /// Reads a signed 8 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_i8,
+        read_i8,
+        i8
+    );
+
+    fn_expect!(
+        /// Reads an unsigned 16 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        /// use std::io::ErrorKind;
+        ///
+        /// let mut rdr = ByteOrdered::be(&[0xFE, 0xFF, 0x00, 0x00][..]);
+        /// // byte order mark
+        /// rdr.expect_u16(0xFEFF).unwrap();
+        /// // reserved field
+        /// let e = rdr.expect_u16(0x0001).unwrap_err();
+        /// assert_eq!(e.kind(), ErrorKind::InvalidData);
+        /// ```
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_u16,
+        read_u16,
+        u16
+    );
+
+    fn_expect!(
+        /// Reads a signed 16 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_i16,
+        read_i16,
+        i16
+    );
+
+    fn_expect!(
+        /// Reads an unsigned 32 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_u32,
+        read_u32,
+        u32
+    );
+
+    fn_expect!(
+        /// Reads a signed 32 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_i32,
+        read_i32,
+        i32
+    );
+
+    fn_expect!(
+        /// Reads an unsigned 64 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_u64,
+        read_u64,
+        u64
+    );
+
+    fn_expect!(
+        /// Reads a signed 64 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_i64,
+        read_i64,
+        i64
+    );
+
+    fn_expect!(
+        /// Reads an unsigned 128 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_u128,
+        read_u128,
+        u128
+    );
+
+    fn_expect!(
+        /// Reads a signed 128 bit integer from the underlying reader
+        /// and checks that it matches the expected value.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned
+        /// if the value read is not the expected one.
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        expect_i128,
+        read_i128,
+        i128
+    );
+}
+
+/// Private macro for reading a fixed-size array of primitive values.
+macro_rules! fn_read_array {
+    ($(#[$attr:meta])* $method:ident, $read_into:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method<const N: usize>(&mut self) -> IoResult<[$ty; N]> {
+            let mut out = [0 as $ty; N];
+            self.$read_into(&mut out)?;
+            Ok(out)
+        }
+    };
+}
+
+/// Private macro for reading a fixed-size grid of primitive values,
+/// one row at a time.
+macro_rules! fn_read_array_2d {
+    ($(#[$attr:meta])* $method:ident, $read_into:ident, $ty:ty) => {
+        $(#[$attr])*
+        pub fn $method<const ROWS: usize, const COLS: usize>(
+            &mut self,
+        ) -> IoResult<[[$ty; COLS]; ROWS]> {
+            let mut out = [[0 as $ty; COLS]; ROWS];
+            for row in out.iter_mut() {
+                self.$read_into(row)?;
+            }
+            Ok(out)
+        }
+    };
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: ReadBytesExt,
+    E: Endian,
+{
+    fn_read_array!(
+        /// Reads a fixed-size array of signed 16 bit integers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i16_array,
+        read_i16_into,
+        i16
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of signed 16 bit integers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i16_array_2d,
+        read_i16_into,
+        i16
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of unsigned 16 bit integers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u16_array,
+        read_u16_into,
+        u16
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of unsigned 16 bit integers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u16_array_2d,
+        read_u16_into,
+        u16
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of signed 32 bit integers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i32_array,
+        read_i32_into,
+        i32
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of signed 32 bit integers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i32_array_2d,
+        read_i32_into,
+        i32
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of unsigned 32 bit integers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let mut rdr = ByteOrdered::le(&[1, 0, 0, 0, 2, 0, 0, 0][..]);
+        /// let pair: [u32; 2] = rdr.read_u32_array()?;
+        /// assert_eq!(pair, [1, 2]);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u32_array,
+        read_u32_into,
+        u32
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of unsigned 32 bit integers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u32_array_2d,
+        read_u32_into,
+        u32
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of signed 64 bit integers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i64_array,
+        read_i64_into,
+        i64
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of signed 64 bit integers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i64_array_2d,
+        read_i64_into,
+        i64
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of unsigned 64 bit integers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u64_array,
+        read_u64_into,
+        u64
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of unsigned 64 bit integers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u64_array_2d,
+        read_u64_into,
+        u64
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of signed 128 bit integers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i128_array,
+        read_i128_into,
+        i128
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of signed 128 bit integers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_i128_array_2d,
+        read_i128_into,
+        i128
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of unsigned 128 bit integers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u128_array,
+        read_u128_into,
+        u128
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of unsigned 128 bit integers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_u128_array_2d,
+        read_u128_into,
+        u128
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of IEEE754 single-precision (4 bytes) floating point numbers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f32_array,
+        read_f32_into,
+        f32
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of IEEE754 single-precision (4 bytes) floating point numbers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// # Examples
+        ///
+        /// Read a 4x4 transformation matrix:
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// # let mut data = ByteOrdered::be(Vec::new());
+        /// # for i in 0..16 {
+        /// #     data.write_f32(if i % 5 == 0 { 1.0 } else { 0.0 })?;
+        /// # }
+        /// # let data = data.into_inner();
+        /// let mut rdr = ByteOrdered::be(&data[..]);
+        /// let transform: [[f32; 4]; 4] = rdr.read_f32_array_2d()?;
+        /// assert_eq!(transform[0], [1.0, 0.0, 0.0, 0.0]);
+        /// assert_eq!(transform[3], [0.0, 0.0, 0.0, 1.0]);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f32_array_2d,
+        read_f32_into,
+        f32
+    );
+
+    fn_read_array!(
+        /// Reads a fixed-size array of IEEE754 double-precision (8 bytes) floating point numbers
+        /// from the underlying reader.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f64_array,
+        read_f64_into,
+        f64
+    );
+
+    fn_read_array_2d!(
+        /// Reads a fixed-size grid of IEEE754 double-precision (8 bytes) floating point numbers
+        /// from the underlying reader, in row-major order.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Read::read_exact`].
+        ///
+        /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+        read_f64_array_2d,
+        read_f64_into,
+        f64
+    );
+}
+
+/// Private macro for turning a reader into an iterator
+/// over primitive values until the end of the stream.
+macro_rules! fn_typed_iter {
+    ($(#[$attr:meta])* $method:ident, $read:ident, $ty:ty) => {
+        $(#[$attr])*
+        pub fn $method(mut self) -> impl Iterator<Item = IoResult<$ty>> {
+            let mut done = false;
+            iter::from_fn(move || {
+                if done {
+                    return None;
+                }
+                let mut buf = [0; size_of::<$ty>()];
+                let value = match self.read_exact_or_eof(&mut buf) {
+                    Ok(true) => self.endianness.$read(&buf[..]),
+                    Ok(false) => {
+                        done = true;
+                        return None;
+                    }
+                    Err(e) => Err(e),
+                };
+                done = value.is_err();
+                Some(value)
+            })
+        }
+    };
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: Read,
+    E: Endian,
+{
+    /// Fills the buffer with bytes from the underlying reader,
+    /// unless the reader is already at the end of the stream.
+    ///
+    /// Returns `true` if the buffer was filled completely,
+    /// and `false` if the stream ended before any byte could be read,
+    /// which is a clean end of stream at a record boundary.
+    /// Unlike [`Read::read_exact`],
+    /// this tells the two situations apart
+    /// without losing track of how much was read,
+    /// which makes it a building block for custom framing.
+    /// An empty buffer is always considered filled.
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+    /// if the stream ended after filling only part of the buffer,
+    /// in which case the contents of `buf` are unspecified.
+    /// Interrupted reads are retried,
+    /// and any other error from the reader is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::be(&[1, 2, 3, 4, 5, 6][..]);
+    /// let mut frame = [0; 3];
+    /// let mut frames = Vec::new();
+    /// while rdr.read_exact_or_eof(&mut frame)? {
+    ///     frames.push(frame);
+    /// }
+    /// assert_eq!(frames, vec![[1, 2, 3], [4, 5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+    pub fn read_exact_or_eof(&mut self, buf: &mut [u8]) -> IoResult<bool> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match self.inner.read(&mut buf[filled..]) {
+                Ok(0) if filled == 0 => return Ok(false),
+                Ok(0) => {
+                    return Err(IoError::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    ))
+                }
+                Ok(n) => filled += n,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(true)
+    }
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over signed 16 bit integers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        i16s,
+        read_i16,
+        i16
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over unsigned 16 bit integers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        u16s,
+        read_u16,
+        u16
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over signed 32 bit integers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        i32s,
+        read_i32,
+        i32
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over unsigned 32 bit integers
+        /// until the end of the stream.
+        ///
+        /// This is the typed counterpart of [`Read::bytes`].
+        /// The iterator ends cleanly if the stream ends
+        /// right at the boundary between two values.
+        /// An error is yielded if a read fails
+        /// or the stream ends in the middle of a value,
+        /// after which the iteration stops.
+        /// Interrupted reads are retried.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let data = [0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02];
+        /// let values = ByteOrdered::be(&data[..])
+        ///     .u32s()
+        ///     .collect::<std::io::Result<Vec<u32>>>()?;
+        /// assert_eq!(values, vec![1, 2]);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`Read::bytes`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.bytes
+        u32s,
+        read_u32,
+        u32
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over signed 64 bit integers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        i64s,
+        read_i64,
+        i64
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over unsigned 64 bit integers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        u64s,
+        read_u64,
+        u64
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over signed 128 bit integers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        i128s,
+        read_i128,
+        i128
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over unsigned 128 bit integers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        u128s,
+        read_u128,
+        u128
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over IEEE754 single-precision (4 bytes) floating point numbers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        f32s,
+        read_f32,
+        f32
+    );
+
+    fn_typed_iter!(
+        /// Converts this reader into an iterator over IEEE754 double-precision (8 bytes) floating point numbers
+        /// until the end of the stream.
+        ///
+        /// See [`u32s`](#method.u32s) for more details.
+        f64s,
+        read_f64,
+        f64
+    );
+}
+
+/// Private macro for writing a primitive value
+/// from any type which converts to it without loss.
+macro_rules! fn_write_from {
+    ($(#[$attr:meta])* $method:ident, $write:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method<V>(&mut self, x: V) -> IoResult<()>
+        where
+            V: Into<$ty>,
+        {
+            self.$write(x.into())
+        }
+    };
+}
+
+/// Private macro for writing a primitive value
+/// narrowed from a wider integer type,
+/// failing if it does not fit.
+macro_rules! fn_write_narrowing {
+    ($(#[$attr:meta])* $method:ident, $write:ident, $ty:ty, $from:ty) => {
+        $(#[$attr])*
+        pub fn $method(&mut self, x: $from) -> IoResult<()> {
+            let v = <$ty>::try_from(x).map_err(|_| {
+                IoError::new(
+                    ErrorKind::InvalidData,
+                    format!("{} does not fit in {}", x, stringify!($ty)),
+                )
+            })?;
+            self.$write(v)
+        }
+    };
+}
+
+/// Private macro for writing an unsigned integer
+/// which is replaced by a sentinel value if absent.
+macro_rules! fn_write_opt_sentinel {
+    ($(#[$attr:meta])* $method:ident, $write:ident, $ty:ty) => {
+        $(#[$attr])*
+        pub fn $method(&mut self, x: Option<$ty>, sentinel: $ty) -> IoResult<()> {
+            match x {
+                None => self.$write(sentinel),
+                Some(v) if v == sentinel => Err(IoError::new(
+                    ErrorKind::InvalidInput,
+                    format!("{} is reserved as the sentinel for absent values", v),
+                )),
+                Some(v) => self.$write(v),
+            }
+        }
+    };
+}
+
+/// Private macro for writing a primitive value
+/// preceded by a presence flag.
+macro_rules! fn_write_optional {
+    ($(#[$attr:meta])* $method:ident, $write:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method(&mut self, x: Option<$ty>) -> IoResult<()> {
+            self.write_optional_with(x, |wtr, v| wtr.$write(v))
+        }
+    };
+}
+
+impl<W, E> ByteOrdered<W, E>
+where
+    W: WriteBytesExt,
+    E: Endian,
+{
+    /// Writes a signed 8 bit integer to the underlying writer.
+    ///
+    /// Note that since this writes a single byte, no byte order conversions
+    /// are used. It is included for completeness.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_i8(&mut self, x: i8) -> IoResult<()> {
+        self.inner.write_i8(x)
+    }
+
+    /// Writes an unsigned 8 bit integer to the underlying writer.
+    ///
+    /// Note that since this writes a single byte, no byte order conversions
+    /// are used. It is included for completeness.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u8(&mut self, x: u8) -> IoResult<()> {
+        self.inner.write_u8(x)
+    }
+
+    /// Writes a boolean to the underlying writer,
+    /// as a single byte of value 1 for `true` or 0 for `false`.
+    ///
+    /// As with `write_u8`, no byte order is involved.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_bool(&mut self, b: bool) -> IoResult<()> {
+        self.write_u8(u8::from(b))
+    }
+
+    /// Writes a signed 16 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    ///
+    /// # Examples
+    ///
+    /// Write signed 16 bit big-endian integers to a `Write`:
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// let mut wtr = ByteOrdered::be(Vec::new());
+    /// wtr.write_i16(193).unwrap();
+    /// wtr.write_i16(-132).unwrap();
+    /// assert_eq!(wtr.into_inner(), b"\x00\xc1\xff\x7c");
+    /// ```
+    #[inline]
+    pub fn write_i16(&mut self, x: i16) -> IoResult<()> {
+        self.endianness.write_i16(self.inner.by_ref(), x)
+    }
+
+    /// Writes an unsigned 16 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u16(&mut self, x: u16) -> IoResult<()> {
+        self.endianness.write_u16(self.inner.by_ref(), x)
+    }
+
+    /// Writes a signed 32 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_i32(&mut self, x: i32) -> IoResult<()> {
+        self.endianness.write_i32(self.inner.by_ref(), x)
+    }
+
+    /// Writes an unsigned 32 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u32(&mut self, x: u32) -> IoResult<()> {
+        self.endianness.write_u32(self.inner.by_ref(), x)
+    }
+
+    /// Writes a signed 64 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_i64(&mut self, x: i64) -> IoResult<()> {
+        self.endianness.write_i64(self.inner.by_ref(), x)
+    }
+
+    /// Writes an unsigned 64 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u64(&mut self, x: u64) -> IoResult<()> {
+        self.endianness.write_u64(self.inner.by_ref(), x)
+    }
+
+    /// Writes a signed 128 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_i128(&mut self, x: i128) -> IoResult<()> {
+        self.endianness.write_i128(self.inner.by_ref(), x)
+    }
+
+    /// Writes an unsigned 128 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u128(&mut self, x: u128) -> IoResult<()> {
+        self.endianness.write_u128(self.inner.by_ref(), x)
+    }
+
+    /// Writes the `nbytes` least significant bytes
+    /// of a signed integer to the underlying writer.
+    ///
+    /// A value which does not fit in `nbytes` bytes is truncated
+    /// to its `nbytes` least significant bytes.
+    ///
+    /// This suits formats where the width of a field
+    /// is only known at run time.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 8,
+    /// as in `byteorder`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut wtr = ByteOrdered::be(Vec::new());
+    /// wtr.write_int(-2, 3)?;
+    /// wtr.write_uint(0x1234, 2)?;
+    /// assert_eq!(wtr.into_inner(), [0xFF, 0xFF, 0xFE, 0x12, 0x34]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_int(&mut self, x: i64, nbytes: usize) -> IoResult<()> {
+        self.endianness.write_int(self.inner.by_ref(), x, nbytes)
+    }
+
+    /// Writes the `nbytes` least significant bytes
+    /// of an unsigned integer to the underlying writer.
+    ///
+    /// This suits formats where the width of a field
+    /// is only known at run time.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 8,
+    /// or if the value does not fit in `nbytes` bytes,
+    /// as in `byteorder`.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_uint(&mut self, x: u64, nbytes: usize) -> IoResult<()> {
+        self.endianness.write_uint(self.inner.by_ref(), x, nbytes)
+    }
+
+    /// Writes the `nbytes` least significant bytes
+    /// of a signed 128 bit integer to the underlying writer.
+    ///
+    /// A value which does not fit in `nbytes` bytes is truncated
+    /// to its `nbytes` least significant bytes.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 16,
+    /// as in `byteorder`.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_int128(&mut self, x: i128, nbytes: usize) -> IoResult<()> {
+        self.endianness.write_int128(self.inner.by_ref(), x, nbytes)
+    }
+
+    /// Writes the `nbytes` least significant bytes
+    /// of an unsigned 128 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbytes` is not between 1 and 16,
+    /// or if the value does not fit in `nbytes` bytes,
+    /// as in `byteorder`.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_uint128(&mut self, x: u128, nbytes: usize) -> IoResult<()> {
+        self.endianness
+            .write_uint128(self.inner.by_ref(), x, nbytes)
+    }
+
+    /// Writes a IEEE754 single-precision (4 bytes) floating point number to
+    /// the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_f32(&mut self, x: f32) -> IoResult<()> {
+        self.endianness.write_f32(self.inner.by_ref(), x)
+    }
+
+    /// Writes a IEEE754 double-precision (8 bytes) floating point number to
+    /// the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_f64(&mut self, x: f64) -> IoResult<()> {
+        self.endianness.write_f64(self.inner.by_ref(), x)
+    }
+
+    /// Writes a sequence of signed 16 bit integers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_i16_into(&mut self, src: &[i16]) -> IoResult<()> {
+        self.endianness.write_i16_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of unsigned 16 bit integers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut wtr = ByteOrdered::be(Vec::new());
+    /// wtr.write_u16_into(&[0x0102, 0x0304])?;
+    /// assert_eq!(wtr.into_inner(), [0x01, 0x02, 0x03, 0x04]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u16_into(&mut self, src: &[u16]) -> IoResult<()> {
+        self.endianness.write_u16_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of signed 32 bit integers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_i32_into(&mut self, src: &[i32]) -> IoResult<()> {
+        self.endianness.write_i32_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of unsigned 32 bit integers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u32_into(&mut self, src: &[u32]) -> IoResult<()> {
+        self.endianness.write_u32_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of signed 64 bit integers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_i64_into(&mut self, src: &[i64]) -> IoResult<()> {
+        self.endianness.write_i64_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of unsigned 64 bit integers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u64_into(&mut self, src: &[u64]) -> IoResult<()> {
+        self.endianness.write_u64_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of signed 128 bit integers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_i128_into(&mut self, src: &[i128]) -> IoResult<()> {
+        self.endianness.write_i128_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of unsigned 128 bit integers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_u128_into(&mut self, src: &[u128]) -> IoResult<()> {
+        self.endianness.write_u128_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of IEEE754 single-precision (4 bytes) floating point numbers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_f32_into(&mut self, src: &[f32]) -> IoResult<()> {
+        self.endianness.write_f32_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a sequence of IEEE754 double-precision (8 bytes) floating point numbers to the underlying writer.
+    ///
+    /// The values are encoded into a single buffer,
+    /// which is then written with one call to `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_f64_into(&mut self, src: &[f64]) -> IoResult<()> {
+        self.endianness.write_f64_into(self.inner.by_ref(), src)
+    }
+
+    /// Writes a complex number to the underlying writer,
+    /// as a pair of IEEE754 single-precision (4 bytes) floating point numbers
+    /// with the real part first and the imaginary part second.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    pub fn write_complex_f32(&mut self, (re, im): (f32, f32)) -> IoResult<()> {
+        self.write_f32(re)?;
+        self.write_f32(im)
+    }
+
+    /// Writes a sequence of complex numbers to the underlying writer,
+    /// interleaving the real and imaginary parts
+    /// as IEEE754 single-precision (4 bytes) floating point numbers.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    pub fn write_complex_f32_into(&mut self, src: &[(f32, f32)]) -> IoResult<()> {
+        for &c in src {
+            self.write_complex_f32(c)?;
+        }
+        Ok(())
+    }
+
+    /// Writes an unsigned 24 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the value does not fit in 24 bits.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    #[inline]
+    pub fn write_u24(&mut self, x: u32) -> IoResult<()> {
+        self.endianness.write_u24(self.inner.by_ref(), x)
+    }
+
+    /// Writes a signed 24 bit integer to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if the value does not fit in 24 bits.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    #[inline]
+    pub fn write_i24(&mut self, x: i32) -> IoResult<()> {
+        self.endianness.write_i24(self.inner.by_ref(), x)
+    }
+
+    /// Writes a sequence of unsigned 24 bit integers
+    /// to the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if any of the values does not fit in 24 bits.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_u24_into(&mut self, src: &[u32]) -> IoResult<()> {
+        let big = self.is_big_endian();
+        let mut buf = Vec::with_capacity(src.len() * 3);
+        for &x in src {
+            if !U24_RANGE.contains(&x) {
+                return Err(not_24_bit(x));
+            }
+            buf.extend_from_slice(&u24_to_bytes(x, big));
+        }
+        self.inner.write_all(&buf)
+    }
+
+    /// Writes a sequence of signed 24 bit integers
+    /// to the underlying writer.
+    ///
+    /// This is the usual layout of 24 bit PCM audio samples.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// without writing anything
+    /// if any of the values does not fit in 24 bits.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_i24_into(&mut self, src: &[i32]) -> IoResult<()> {
+        let big = self.is_big_endian();
+        let mut buf = Vec::with_capacity(src.len() * 3);
+        for &x in src {
+            if !I24_RANGE.contains(&x) {
+                return Err(not_24_bit(x));
+            }
+            buf.extend_from_slice(&u24_to_bytes(x as u32 & 0xFF_FFFF, big));
+        }
+        self.inner.write_all(&buf)
+    }
+
+    /// Writes a UTF-16 byte order mark (`U+FEFF`)
+    /// to the underlying writer, in the assumed byte order.
+    ///
+    /// This is `FE FF` in big endian and `FF FE` in little endian,
+    /// so that the data which follows can be tagged with its byte order,
+    /// and later read back with [`detect_u16_bom`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`detect_u16_bom`]: #method.detect_u16_bom
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_order_tag(&mut self) -> IoResult<()> {
+        self.write_u16(0xFEFF)
+    }
+
+    /// Writes a byte order tag to the underlying writer,
+    /// to be read back with [`read_endianness_tag`].
+    ///
+    /// The tag is a single byte holding the stable `u8` representation
+    /// of the given byte order,
+    /// regardless of the one assumed by this wrapper.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    ///
+    /// [`read_endianness_tag`]: #method.read_endianness_tag
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[inline]
+    pub fn write_endianness_tag(&mut self, endianness: Endianness) -> IoResult<()> {
+        self.write_u8(endianness as u8)
+    }
+
+    /// Writes an unsigned integer in the given number format
+    /// to the underlying writer.
+    ///
+    /// The byte order of the number format takes precedence
+    /// over the one assumed by this wrapper.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// if the width of the format is not between 1 and 8,
+    /// or if the value does not fit in that many bytes.
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn write_number(&mut self, format: NumberFormat, x: u64) -> IoResult<()> {
+        let nbytes = format.checked_width()?;
+        if nbytes < 8 && x >> (nbytes * 8) != 0 {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("value {} does not fit in {} bytes", x, nbytes),
+            ));
+        }
+        match format.endianness {
+            Endianness::Little => self.inner.write_uint::<LittleEndian>(x, nbytes),
+            Endianness::Big => self.inner.write_uint::<BigEndian>(x, nbytes),
+        }
+    }
+
+    fn_write_from!(
+        /// Converts a value to a signed 16 bit integer
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_i16_from,
+        write_i16,
+        i16
+    );
+
+    fn_write_from!(
+        /// Converts a value to an unsigned 16 bit integer
+        /// and writes it to the underlying writer.
+        ///
+        /// Only lossless conversions are accepted,
+        /// so that narrower values can be written without casting.
+        /// [`write_u16`] remains the method of choice
+        /// for unsuffixed integer literals.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// let flags: u8 = 0x80;
+        /// let mut wtr = ByteOrdered::be(Vec::new());
+        /// wtr.write_u16_from(flags)?;
+        /// wtr.write_u16_from(true)?;
+        /// assert_eq!(wtr.into_inner(), [0x00, 0x80, 0x00, 0x01]);
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`write_u16`]: #method.write_u16
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_u16_from,
+        write_u16,
+        u16
+    );
+
+    fn_write_from!(
+        /// Converts a value to a signed 32 bit integer
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_i32_from,
+        write_i32,
+        i32
+    );
+
+    fn_write_from!(
+        /// Converts a value to an unsigned 32 bit integer
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_u32_from,
+        write_u32,
+        u32
+    );
+
+    fn_write_from!(
+        /// Converts a value to a signed 64 bit integer
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_i64_from,
+        write_i64,
+        i64
+    );
+
+    fn_write_from!(
+        /// Converts a value to an unsigned 64 bit integer
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_u64_from,
+        write_u64,
+        u64
+    );
+
+    fn_write_from!(
+        /// Converts a value to a signed 128 bit integer
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_i128_from,
+        write_i128,
+        i128
+    );
+
+    fn_write_from!(
+        /// Converts a value to an unsigned 128 bit integer
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_u128_from,
+        write_u128,
+        u128
+    );
+
+    fn_write_from!(
+        /// Converts a value to a IEEE754 single-precision (4 bytes) floating point number
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_f32_from,
+        write_f32,
+        f32
+    );
+
+    fn_write_from!(
+        /// Converts a value to a IEEE754 double-precision (8 bytes) floating point number
+        /// and writes it to the underlying writer.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_f64_from,
+        write_f64,
+        f64
+    );
+
+    fn_write_narrowing!(
+        /// Writes an unsigned 16 bit integer to the underlying writer
+        /// as an unsigned 8 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_u8_from_u16,
+        write_u8,
+        u8,
+        u16
+    );
+
+    fn_write_narrowing!(
+        /// Writes an unsigned 32 bit integer to the underlying writer
+        /// as an unsigned 8 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_u8_from_u32,
+        write_u8,
+        u8,
+        u32
+    );
+
+    fn_write_narrowing!(
+        /// Writes an unsigned 32 bit integer to the underlying writer
+        /// as an unsigned 16 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_u16_from_u32,
+        write_u16,
+        u16,
+        u32
+    );
+
+    fn_write_narrowing!(
+        /// Writes an unsigned 64 bit integer to the underlying writer
+        /// as an unsigned 16 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_u16_from_u64,
+        write_u16,
+        u16,
+        u64
+    );
+
+    fn_write_narrowing!(
+        /// Writes an unsigned 64 bit integer to the underlying writer
+        /// as an unsigned 32 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_u32_from_u64,
+        write_u32,
+        u32,
+        u64
+    );
+
+    fn_write_narrowing!(
+        /// Writes a signed 16 bit integer to the underlying writer
+        /// as a signed 8 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_i8_from_i16,
+        write_i8,
+        i8,
+        i16
+    );
+
+    fn_write_narrowing!(
+        /// Writes a signed 32 bit integer to the underlying writer
+        /// as a signed 8 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_i8_from_i32,
+        write_i8,
+        i8,
+        i32
+    );
+
+    fn_write_narrowing!(
+        /// Writes a signed 32 bit integer to the underlying writer
+        /// as a signed 16 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_i16_from_i32,
+        write_i16,
+        i16,
+        i32
+    );
+
+    fn_write_narrowing!(
+        /// Writes a signed 64 bit integer to the underlying writer
+        /// as a signed 16 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_i16_from_i64,
+        write_i16,
+        i16,
+        i64
+    );
+
+    fn_write_narrowing!(
+        /// Writes a signed 64 bit integer to the underlying writer
+        /// as a signed 32 bit integer,
+        /// provided that the value fits.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidData`] is returned,
+        /// and nothing is written,
+        /// if the value is out of the range of the narrower type.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+        write_i32_from_i64,
+        write_i32,
+        i32,
+        i64
+    );
+
+    fn_write_opt_sentinel!(
+        /// Writes an optional unsigned 16 bit integer
+        /// to the underlying writer,
+        /// with the given sentinel value standing for `None`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned,
+        /// and nothing is written,
+        /// if the value is present but equal to the sentinel,
+        /// since it would be read back as absent.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        write_u16_opt_sentinel,
+        write_u16,
+        u16
+    );
+
+    fn_write_opt_sentinel!(
+        /// Writes an optional unsigned 32 bit integer
+        /// to the underlying writer,
+        /// with the given sentinel value standing for `None`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned,
+        /// and nothing is written,
+        /// if the value is present but equal to the sentinel,
+        /// since it would be read back as absent.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        write_u32_opt_sentinel,
+        write_u32,
+        u32
+    );
+
+    fn_write_opt_sentinel!(
+        /// Writes an optional unsigned 64 bit integer
+        /// to the underlying writer,
+        /// with the given sentinel value standing for `None`.
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        /// An error of kind [`ErrorKind::InvalidInput`] is returned,
+        /// and nothing is written,
+        /// if the value is present but equal to the sentinel,
+        /// since it would be read back as absent.
+        ///
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+        write_u64_opt_sentinel,
+        write_u64,
+        u64
+    );
+
+    /// Writes a presence flag to the underlying writer,
+    /// then encodes the value with the given function if it is present,
+    /// to be read back with [`read_optional_with`].
+    ///
+    /// The flag is written as `1` if the value is present
+    /// and `0` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Write::write_all`]
+    /// while writing the flag,
+    /// and any error produced by `encode`.
+    ///
+    /// [`read_optional_with`]: #method.read_optional_with
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    pub fn write_optional_with<T, F>(&mut self, x: Option<T>, encode: F) -> IoResult<()>
+    where
+        F: FnOnce(&mut Self, T) -> IoResult<()>,
+    {
+        match x {
+            None => self.write_u8(0),
+            Some(v) => {
+                self.write_u8(1)?;
+                encode(self, v)
+            }
+        }
+    }
+
+    fn_write_optional!(
+        /// Writes a presence flag to the underlying writer,
+        /// followed by an unsigned 16 bit integer if the value is present,
+        /// to be read back with [`read_optional_u16`].
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`read_optional_u16`]: #method.read_optional_u16
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_optional_u16,
+        write_u16,
+        u16
+    );
+
+    fn_write_optional!(
+        /// Writes a presence flag to the underlying writer,
+        /// followed by an unsigned 32 bit integer if the value is present,
+        /// to be read back with [`read_optional_u32`].
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`read_optional_u32`]: #method.read_optional_u32
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_optional_u32,
+        write_u32,
+        u32
+    );
+
+    fn_write_optional!(
+        /// Writes a presence flag to the underlying writer,
+        /// followed by an unsigned 64 bit integer if the value is present,
+        /// to be read back with [`read_optional_u64`].
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`Write::write_all`].
+        ///
+        /// [`read_optional_u64`]: #method.read_optional_u64
+        /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+        write_optional_u64,
+        write_u64,
+        u64
+    );
+}
+
+impl<T, E> BufRead for ByteOrdered<T, E>
+where
+    T: BufRead,
+{
+    #[inline]
+    fn fill_buf(&mut self) -> IoResult<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt)
+    }
+
+    #[inline]
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> IoResult<usize> {
+        self.inner.read_until(byte, buf)
+    }
+
+    #[inline]
+    fn read_line(&mut self, buf: &mut String) -> IoResult<usize> {
+        self.inner.read_line(buf)
+    }
+}
+
+impl<T, E> Seek for ByteOrdered<T, E>
+where
+    T: Seek,
+{
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+/// The bytes of a partially read element,
+/// carried between calls to [`ByteOrdered::read_u32_into_resumable`].
+///
+/// A fresh state is created with `ResumeState::default()`
+/// and must be passed to every call resuming the same sequence.
+///
+/// [`ByteOrdered::read_u32_into_resumable`]: struct.ByteOrdered.html#method.read_u32_into_resumable
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ResumeState {
+    buf: [u8; 4],
+    len: usize,
+}
+
+impl ResumeState {
+    /// Retrieves the number of bytes of the next element
+    /// which were already consumed from the reader.
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.len
+    }
+}
+
+/// A saved position in a seekable stream,
+/// created by [`ByteOrdered::checkpoint`].
+///
+/// A checkpoint is only meaningful for the stream which created it.
+///
+/// [`ByteOrdered::checkpoint`]: struct.ByteOrdered.html#method.checkpoint
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checkpoint(u64);
+
+impl Checkpoint {
+    /// Retrieves the stream position saved in this checkpoint,
+    /// in bytes from the start of the stream.
+    #[inline]
+    pub fn position(self) -> u64 {
+        self.0
+    }
+}
+
+impl<S, E> ByteOrdered<S, E>
+where
+    S: Seek,
+{
+    /// Saves the current position of the stream,
+    /// so that it can be returned to later with [`restore`].
+    ///
+    /// This is the usual companion of backtracking parsers:
+    /// save a checkpoint, attempt to parse,
+    /// and restore the checkpoint if the attempt fails.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Seek::seek`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Cursor;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(Cursor::new(vec![0x01, 0x00, 0x02, 0x00]));
+    /// let cp = rdr.checkpoint()?;
+    /// if rdr.read_u32()? != 0xFFFF_FFFF {
+    ///     // not what we expected, try something else
+    ///     rdr.restore(cp)?;
+    /// }
+    /// assert_eq!(rdr.read_u16()?, 1);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`restore`]: #method.restore
+    /// [`Seek::seek`]: https://doc.rust-lang.org/std/io/trait.Seek.html#tymethod.seek
+    #[inline]
+    pub fn checkpoint(&mut self) -> IoResult<Checkpoint> {
+        self.inner.stream_position().map(Checkpoint)
+    }
+
+    /// Seeks the stream back to a position saved with [`checkpoint`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Seek::seek`].
+    ///
+    /// [`checkpoint`]: #method.checkpoint
+    /// [`Seek::seek`]: https://doc.rust-lang.org/std/io/trait.Seek.html#tymethod.seek
+    #[inline]
+    pub fn restore(&mut self, cp: Checkpoint) -> IoResult<()> {
+        self.inner.seek(SeekFrom::Start(cp.0)).map(|_| ())
+    }
+}
+
+impl<S, E> ByteOrdered<S, E>
+where
+    S: ReadBytesExt + WriteBytesExt,
+    E: Endian,
+{
+    /// Reads a value with the given reading function,
+    /// then writes it back with the given writing function,
+    /// both in the assumed byte order.
+    /// The value is returned on success.
+    ///
+    /// This is a convenience for echo-like exchanges in protocols
+    /// operating over a duplex stream.
+    ///
+    /// # Errors
+    ///
+    /// Errors from either of the two functions are returned as is.
+    /// If reading fails, nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Cursor;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut stream = ByteOrdered::be(Cursor::new(vec![0, 0, 1, 0, 0, 0, 0, 0]));
+    /// let v = stream.transfer(ByteOrdered::read_u32, ByteOrdered::write_u32)?;
+    /// assert_eq!(v, 256);
+    /// assert_eq!(stream.into_inner().into_inner(), [0, 0, 1, 0, 0, 0, 1, 0]);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    #[inline]
+    pub fn transfer<V, F, G>(&mut self, read: F, write: G) -> IoResult<V>
+    where
+        V: Copy,
+        F: FnOnce(&mut Self) -> IoResult<V>,
+        G: FnOnce(&mut Self, V) -> IoResult<()>,
+    {
+        let value = read(self)?;
+        write(self, value)?;
+        Ok(value)
+    }
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: Read + Seek,
+    E: Endian,
+{
+    /// Repeatedly decodes records with the given function
+    /// until exactly `budget` bytes have been consumed from the reader.
+    ///
+    /// This is useful for sections with a declared total size
+    /// and a variable number of records.
+    /// The bytes consumed are measured through the stream position,
+    /// so the decoder is free to read and seek as it sees fit,
+    /// as long as each record moves the stream forward.
+    ///
+    /// # Errors
+    ///
+    /// Errors from `decode` or from seeking are returned as is.
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if a record ends past the given budget,
+    /// or if a record did not move the stream position forward.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::{Cursor, Read};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// // a section of 6 bytes with length-prefixed strings, then more data
+    /// let data = Cursor::new(b"\x00\x01a\x00\x01b\xFF".to_vec());
+    /// let mut rdr = ByteOrdered::be(data);
+    /// let names = rdr.read_within(6, |rdr| {
+    ///     let len = rdr.read_u16()?;
+    ///     let mut name = vec![0; len as usize];
+    ///     rdr.read_exact(&mut name)?;
+    ///     Ok(name)
+    /// })?;
+    /// assert_eq!(names, vec![b"a".to_vec(), b"b".to_vec()]);
+    /// assert_eq!(rdr.read_u8()?, 0xFF);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_within<T, F>(&mut self, budget: u64, mut decode: F) -> IoResult<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> IoResult<T>,
+    {
+        let start = self.inner.stream_position()?;
+        let mut consumed = 0;
+        let mut records = Vec::new();
+        while consumed < budget {
+            let record = decode(self)?;
+            let position = self.inner.stream_position()?;
+            match position.checked_sub(start) {
+                Some(c) if c > budget => {
+                    return Err(IoError::new(
+                        ErrorKind::InvalidData,
+                        "record exceeds the remaining byte budget",
+                    ));
+                }
+                Some(c) if c > consumed => consumed = c,
+                _ => {
+                    return Err(IoError::new(
+                        ErrorKind::InvalidData,
+                        "record did not advance the stream",
+                    ));
+                }
+            }
+            records.push(record);
+        }
+        Ok(records)
+    }
+
+    /// Reads unsigned 32 bit integers from the underlying reader
+    /// for as long as they satisfy the given predicate.
+    ///
+    /// The first value which fails the predicate is not consumed:
+    /// since it can only be inspected after reading it,
+    /// the reader is then sought back by 4 bytes,
+    /// hence the `Seek` requirement.
+    /// As such, the stream is left positioned
+    /// right before the value which stopped the sequence.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Read::read_exact`]
+    /// and [`Seek::seek`].
+    /// Reaching the end of the stream before a value fails the predicate
+    /// is an error of kind [`ErrorKind::UnexpectedEof`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Cursor;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// // offsets in ascending order, followed by a sentinel
+    /// let data = Cursor::new(vec![0, 0, 0, 4, 0, 0, 0, 9, 0xFF, 0xFF, 0xFF, 0xFF]);
+    /// let mut rdr = ByteOrdered::be(data);
+    /// let offsets = rdr.read_u32_while(|x| x != 0xFFFF_FFFF)?;
+    /// assert_eq!(offsets, vec![4, 9]);
+    /// assert_eq!(rdr.read_u32()?, 0xFFFF_FFFF);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+    /// [`Seek::seek`]: https://doc.rust-lang.org/std/io/trait.Seek.html#tymethod.seek
+    /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+    pub fn read_u32_while<F>(&mut self, mut pred: F) -> IoResult<Vec<u32>>
+    where
+        F: FnMut(u32) -> bool,
+    {
+        let mut values = Vec::new();
+        loop {
+            let value = self.read_u32()?;
+            if !pred(value) {
+                self.inner.seek(SeekFrom::Current(-4))?;
+                return Ok(values);
+            }
+            values.push(value);
+        }
+    }
+
+    /// Reads an array of `count` fixed-size records,
+    /// the start of each one being `stride` bytes after the previous one.
+    ///
+    /// Each record is decoded with the given function,
+    /// after which the reader is sought to the start of the next record,
+    /// skipping any padding left in between.
+    /// This is the typical layout of an array of C structs,
+    /// where the stride is the size of the struct including its padding.
+    /// The stream is left positioned right after
+    /// the padding of the last record.
+    ///
+    /// # Errors
+    ///
+    /// Any error produced by `decode` or by [`Seek::seek`] is returned.
+    /// An error of kind [`ErrorKind::InvalidData`] is returned
+    /// if decoding a record consumed more than `stride` bytes,
+    /// or moved the stream backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Cursor;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// // struct { uint16_t id; uint8_t flags; /* 1 byte of padding */ }
+    /// let data = Cursor::new(vec![0x00, 0x01, 0x0A, 0x00, 0x00, 0x02, 0x0B, 0x00, 0xFF]);
+    /// let mut rdr = ByteOrdered::be(data);
+    /// let records = rdr.read_strided(2, 4, |rdr| Ok((rdr.read_u16()?, rdr.read_u8()?)))?;
+    /// assert_eq!(records, vec![(1, 0x0A), (2, 0x0B)]);
+    /// assert_eq!(rdr.read_u8()?, 0xFF);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Seek::seek`]: https://doc.rust-lang.org/std/io/trait.Seek.html#tymethod.seek
+    /// [`ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn read_strided<T, F>(
+        &mut self,
+        count: usize,
+        stride: usize,
+        mut decode: F,
+    ) -> IoResult<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> IoResult<T>,
+    {
+        let stride = stride as u64;
+        let mut records = Vec::new();
+        for _ in 0..count {
+            let start = self.inner.stream_position()?;
+            let record = decode(self)?;
+            let position = self.inner.stream_position()?;
+            match position.checked_sub(start) {
+                Some(size) if size <= stride => {}
+                Some(size) => {
+                    return Err(IoError::new(
+                        ErrorKind::InvalidData,
+                        format!("record of {} bytes exceeds the stride of {}", size, stride),
+                    ));
+                }
+                None => {
+                    return Err(IoError::new(
+                        ErrorKind::InvalidData,
+                        "record moved the stream backwards",
+                    ));
+                }
+            }
+            self.inner.seek(SeekFrom::Start(start + stride))?;
+            records.push(record);
+        }
+        Ok(records)
+    }
+}
+
+impl<E> ByteOrdered<Cursor<Vec<u8>>, E>
+where
+    E: Copy,
+{
+    /// Creates an independent reader over the same in-memory data,
+    /// starting at the current position and assuming the same byte order.
+    ///
+    /// Reading from or seeking the returned wrapper does not affect this one,
+    /// which makes it handy for speculative parsing:
+    /// try one interpretation on the clone,
+    /// and fall back to the original if it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Cursor;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let rdr = ByteOrdered::be(Cursor::new(vec![0x00, 0x01, 0x02, 0x03]));
+    /// let mut attempt = rdr.clone_reader();
+    /// assert_eq!(attempt.read_u32()?, 0x0001_0203);
+    /// // the original reader was left untouched
+    /// assert_eq!(rdr.into_inner().position(), 0);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    #[inline]
+    pub fn clone_reader(&self) -> ByteOrdered<Cursor<Vec<u8>>, E> {
+        ByteOrdered {
+            inner: self.inner.clone(),
+            endianness: self.endianness,
+        }
+    }
+}
+
+impl<E> ByteOrdered<&[u8], E>
+where
+    E: Copy,
+{
+    /// Splits the remaining data at the given position,
+    /// returning a reader over the first `n` bytes
+    /// and a reader over the rest,
+    /// both assuming the same byte order.
+    ///
+    /// No data is copied,
+    /// which makes this handy for parsing length-delimited records
+    /// independently from what follows them.
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+    /// if `n` is greater than the length of the remaining data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&[0x02, 0x00, 0x07, 0x00, 0x2A][..]);
+    /// let len = rdr.read_u16()? as usize;
+    /// let (mut record, mut rest) = rdr.split_at(len)?;
+    /// assert_eq!(record.read_u16()?, 7);
+    /// assert_eq!(rest.read_u8()?, 42);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+    pub fn split_at(self, n: usize) -> IoResult<(Self, Self)> {
+        if n > self.inner.len() {
+            return Err(IoError::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "cannot split at {} with only {} bytes remaining",
+                    n,
+                    self.inner.len()
+                ),
+            ));
+        }
+        let (head, tail) = self.inner.split_at(n);
+        let head = ByteOrdered {
+            inner: head,
+            endianness: self.endianness,
+        };
+        let tail = ByteOrdered {
+            inner: tail,
+            endianness: self.endianness,
+        };
+        Ok((head, tail))
+    }
+}
+
+/// Private macro for reading a primitive value
+/// at an offset of an in-memory slice.
+macro_rules! fn_read_at {
+    ($(#[$attr:meta])* $method:ident, $read:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $method(&self, offset: usize) -> IoResult<$ty> {
+            let bytes = self
+                .inner
+                .get(offset..)
+                .and_then(|rest| rest.get(..size_of::<$ty>()))
+                .ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::UnexpectedEof,
+                        format!(
+                            "cannot read {} at offset {} of {} bytes",
+                            stringify!($ty),
+                            offset,
+                            self.inner.len()
+                        ),
+                    )
+                })?;
+            self.endianness.$read(bytes)
+        }
+    };
+}
+
+impl<E> ByteOrdered<&[u8], E>
+where
+    E: Endian,
+{
+    fn_read_at!(
+        /// Reads a signed 16 bit integer at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_i16_at,
+        read_i16,
+        i16
+    );
+
+    fn_read_at!(
+        /// Reads an unsigned 16 bit integer at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_u16_at,
+        read_u16,
+        u16
+    );
+
+    fn_read_at!(
+        /// Reads a signed 32 bit integer at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_i32_at,
+        read_i32,
+        i32
+    );
+
+    fn_read_at!(
+        /// Reads an unsigned 32 bit integer at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use byteordered::ByteOrdered;
+        ///
+        /// # fn run() -> std::io::Result<()> {
+        /// // a table of offsets in a memory mapped file
+        /// let data = [0xFF, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x20];
+        /// let rdr = ByteOrdered::be(&data[..]);
+        /// assert_eq!(rdr.read_u32_at(1)?, 0x10);
+        /// assert_eq!(rdr.read_u32_at(5)?, 0x20);
+        /// assert!(rdr.read_u32_at(6).is_err());
+        /// # Ok(())
+        /// # }
+        /// # run().unwrap();
+        /// ```
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_u32_at,
+        read_u32,
+        u32
+    );
+
+    fn_read_at!(
+        /// Reads a signed 64 bit integer at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_i64_at,
+        read_i64,
+        i64
+    );
+
+    fn_read_at!(
+        /// Reads an unsigned 64 bit integer at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_u64_at,
+        read_u64,
+        u64
+    );
+
+    fn_read_at!(
+        /// Reads a signed 128 bit integer at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_i128_at,
+        read_i128,
+        i128
+    );
+
+    fn_read_at!(
+        /// Reads an unsigned 128 bit integer at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_u128_at,
+        read_u128,
+        u128
+    );
+
+    fn_read_at!(
+        /// Reads a IEEE754 single-precision (4 bytes) floating point number at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_f32_at,
+        read_f32,
+        f32
+    );
+
+    fn_read_at!(
+        /// Reads a IEEE754 double-precision (8 bytes) floating point number at the given offset
+        /// of the remaining data,
+        /// without moving the reading position.
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`ErrorKind::UnexpectedEof`] is returned
+        /// if the value would extend past the end of the data.
+        ///
+        /// [`ErrorKind::UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+        read_f64_at,
+        read_f64,
+        f64
+    );
+}
+
+impl<R, E> ByteOrdered<R, E>
+where
+    R: Read,
+{
+    /// Wraps the inner reader in a [`BufReader`],
+    /// retaining the assumed byte order.
+    ///
+    /// Typed reads issue one small read per value,
+    /// which is inefficient over readers without buffering of their own,
+    /// such as files, sockets,
+    /// and most decompression streams.
+    /// The resulting wrapper also implements [`BufRead`].
+    ///
+    /// Once done, the original reader can be recovered
+    /// by unwrapping each layer in turn,
+    /// as in `rdr.into_innermost().into_inner()`.
+    /// Note that any data already buffered is lost in the process.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::BufRead;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut rdr = ByteOrdered::le(&b"\x02\x00name\n"[..]).into_buffered_reader();
+    /// assert_eq!(rdr.read_u16()?, 2);
+    /// let mut line = String::new();
+    /// rdr.read_line(&mut line)?;
+    /// assert_eq!(line, "name\n");
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`BufReader`]: https://doc.rust-lang.org/std/io/struct.BufReader.html
+    /// [`BufRead`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+    #[inline]
+    pub fn into_buffered_reader(self) -> ByteOrdered<BufReader<R>, E> {
+        ByteOrdered {
+            inner: BufReader::new(self.inner),
+            endianness: self.endianness,
+        }
+    }
+}
+
+impl<R, E> ByteOrdered<BufReader<R>, E> {
+    /// Obtains the bytes currently held in the reader's buffer,
+    /// without reading more data.
+    ///
+    /// This allows decoding ahead of the current position
+    /// without consuming anything.
+    /// See also [`ensure_buffered`].
+    ///
+    /// [`ensure_buffered`]: #method.ensure_buffered
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        self.inner.buffer()
+    }
+
+    /// Obtains the capacity of the reader's buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+}
+
+impl<R, E> ByteOrdered<BufReader<R>, E>
+where
+    R: Seek,
+{
+    /// Seeks relative to the current position of the buffered reader,
+    /// by forwarding to [`BufReader::seek_relative`].
+    ///
+    /// Unlike seeking with [`SeekFrom::Current`],
+    /// the internal buffer is kept
+    /// if the new position falls within it,
+    /// which makes small skips considerably cheaper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Cursor;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let data = Cursor::new(vec![0x00, 0x01, 0xFF, 0xFF, 0x00, 0x02]);
+    /// let mut rdr = ByteOrdered::be(data).into_buffered_reader();
+    /// assert_eq!(rdr.read_u16()?, 1);
+    /// // skip padding
+    /// rdr.seek_relative(2)?;
+    /// assert_eq!(rdr.read_u16()?, 2);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`BufReader::seek_relative`]: https://doc.rust-lang.org/std/io/struct.BufReader.html#method.seek_relative
+    /// [`SeekFrom::Current`]: https://doc.rust-lang.org/std/io/enum.SeekFrom.html#variant.Current
+    #[inline]
+    pub fn seek_relative(&mut self, offset: i64) -> IoResult<()> {
+        self.inner.seek_relative(offset)
+    }
+}
+
+impl<R, E> ByteOrdered<BufReader<R>, E>
+where
+    R: Read + Seek,
+{
+    /// Ensures that at least `n` bytes are held in the reader's buffer,
+    /// unless the end of the stream is reached first,
+    /// and returns the buffered bytes.
+    ///
+    /// Buffered readers only fetch more data
+    /// once their buffer is exhausted,
+    /// so a peek over a few bytes near the end of the buffer
+    /// would otherwise come up short.
+    /// When fewer than `n` bytes are buffered,
+    /// the buffer is discarded by seeking to the current position
+    /// and refilled from there,
+    /// without changing the position of the reader.
+    /// The refill is a single read on the inner reader,
+    /// which fills the buffer in full
+    /// for files and in-memory sources.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as
+    /// [`Seek::seek`] and [`BufRead::fill_buf`].
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned
+    /// if `n` is greater than the capacity of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::{ByteOrdered, Endian, Endianness};
+    /// use std::io::{BufReader, Cursor};
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let data = Cursor::new(vec![0, 0, 0, 0, 0, 0, 0, 1, 0xFF]);
+    /// let mut rdr = ByteOrdered::be(BufReader::with_capacity(8, data));
+    /// rdr.read_u8()?;
+    /// // peek the next 64-bit integer
+    /// let buf = rdr.ensure_buffered(8)?;
+    /// assert_eq!(Endianness::Big.read_u64(&buf[..8])?, 0x1FF);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`Seek::seek`]: https://doc.rust-lang.org/std/io/trait.Seek.html#tymethod.seek
+    /// [`BufRead::fill_buf`]: https://doc.rust-lang.org/std/io/trait.BufRead.html#tymethod.fill_buf
+    /// [`ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn ensure_buffered(&mut self, n: usize) -> IoResult<&[u8]> {
+        if n > self.inner.capacity() {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "cannot buffer {} bytes with a capacity of {}",
+                    n,
+                    self.inner.capacity()
+                ),
+            ));
+        }
+        if self.inner.buffer().len() < n {
+            // unlike `stream_position`, this discards the buffer
+            #[allow(clippy::seek_from_current)]
+            self.inner.seek(SeekFrom::Current(0))?;
+        }
+        self.inner.fill_buf()
+    }
+}
+
+impl<W, E> ByteOrdered<W, E>
+where
+    W: Write,
+{
+    /// Wraps the inner writer in a [`BufWriter`] with the given capacity,
+    /// retaining the assumed byte order.
+    ///
+    /// Typed writes are then accumulated in memory
+    /// and passed on to the inner writer
+    /// only once the buffer cannot take any more data,
+    /// or when explicitly flushed.
+    /// This considerably reduces the number of calls to the inner writer,
+    /// such as system calls on sockets and files.
+    /// The wrapper remains seekable if the inner writer is,
+    /// in which case pending data is written before seeking.
+    ///
+    /// Dropping the wrapper attempts to write any pending data,
+    /// but errors at that point are ignored.
+    /// Call [`flush`] or [`into_inner_buffered`] when done
+    /// to ensure that every error is reported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::Write;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut wtr = ByteOrdered::le(Vec::new()).with_write_buffer(64);
+    /// wtr.write_u32(1)?;
+    /// wtr.write_u32(2)?;
+    /// // nothing was written to the vector yet
+    /// assert!(wtr.inner_mut().get_ref().is_empty());
+    /// wtr.flush()?;
+    /// assert_eq!(wtr.inner_mut().get_ref().len(), 8);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    ///
+    /// [`BufWriter`]: https://doc.rust-lang.org/std/io/struct.BufWriter.html
+    /// [`flush`]: #method.flush
+    /// [`into_inner_buffered`]: #method.into_inner_buffered
+    #[inline]
+    pub fn with_write_buffer(self, cap: usize) -> ByteOrdered<BufWriter<W>, E> {
+        ByteOrdered {
+            inner: BufWriter::with_capacity(cap, self.inner),
+            endianness: self.endianness,
+        }
+    }
+}
+
+impl<W, E> ByteOrdered<BufWriter<W>, E>
+where
+    W: Write,
+{
+    /// Flushes the inner [`BufWriter`] and recovers the writer underneath it.
+    /// Information about the assumed byte order is discarded.
+    ///
+    /// # Errors
+    ///
+    /// If flushing the buffered data fails,
+    /// an [`IntoInnerError`] is returned,
+    /// from which the buffered writer can still be recovered
+    /// along with the data not yet written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteordered::ByteOrdered;
+    /// use std::io::BufWriter;
+    ///
+    /// let mut wtr = ByteOrdered::be(BufWriter::new(Vec::new()));
+    /// wtr.write_u16(0x0102).unwrap();
+    /// let data = wtr.into_inner_buffered().unwrap();
+    /// assert_eq!(data, [0x01, 0x02]);
+    /// ```
+    ///
+    /// [`BufWriter`]: https://doc.rust-lang.org/std/io/struct.BufWriter.html
+    /// [`IntoInnerError`]: https://doc.rust-lang.org/std/io/struct.IntoInnerError.html
+    #[inline]
+    pub fn into_inner_buffered(self) -> Result<W, IntoInnerError<BufWriter<W>>> {
+        self.inner.into_inner()
+    }
+}
+
+impl<E> ByteOrdered<File, E> {
+    /// Flushes the file and waits for all of its data and metadata
+    /// to reach the storage device,
+    /// by forwarding to [`File::sync_all`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as
+    /// [`Write::flush`] and [`File::sync_all`].
+    ///
+    /// [`File::sync_all`]: https://doc.rust-lang.org/std/fs/struct.File.html#method.sync_all
+    /// [`Write::flush`]: https://doc.rust-lang.org/std/io/trait.Write.html#tymethod.flush
+    pub fn sync_all(&mut self) -> IoResult<()> {
+        self.inner.flush()?;
+        self.inner.sync_all()
+    }
+}
+
+impl<E> ByteOrdered<BufWriter<File>, E> {
+    /// Writes out the buffered data,
+    /// then waits for all of the file's data and metadata
+    /// to reach the storage device,
+    /// by forwarding to [`File::sync_all`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as
+    /// [`Write::flush`] and [`File::sync_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use byteordered::ByteOrdered;
+    /// use std::fs::File;
+    /// use std::io::BufWriter;
+    ///
+    /// # fn run() -> std::io::Result<()> {
+    /// let mut wtr = ByteOrdered::le(BufWriter::new(File::create("out.bin")?));
+    /// wtr.write_u32(0xCAFE_BABE)?;
+    /// wtr.sync_all()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`File::sync_all`]: https://doc.rust-lang.org/std/fs/struct.File.html#method.sync_all
+    /// [`Write::flush`]: https://doc.rust-lang.org/std/io/trait.Write.html#tymethod.flush
+    pub fn sync_all(&mut self) -> IoResult<()> {
+        self.inner.flush()?;
+        self.inner.get_ref().sync_all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // TODO test moar
+    use super::{checked_len, ByteOrdered, LenWidth, NumberFormat, ResumeState};
+    use base::{Endian, Endianness, StaticEndianness};
+    use std::collections::VecDeque;
+    use std::fs::{self, File};
+    use std::io::{
+        BufReader, BufWriter, Cursor, Error as IoError, ErrorKind, Read, Result as IoResult, Write,
+    };
+    use std::mem::MaybeUninit;
+    static TEST_BYTES: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x21, 0x43, 0x65, 0x87];
+
+    static TEST_U64DATA_LE: &[u64] = &[0x87654321_78563412];
+    static TEST_U64DATA_BE: &[u64] = &[0x12345678_21436587];
+
+    /// A writer which counts the calls made to it.
+    struct Counting(Vec<u8>, usize);
+
+    impl Write for Counting {
+        fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+            self.1 += 1;
+            self.0.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_u64() {
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::le(&mut data);
+        let words = [reader.read_u64().unwrap()];
+        assert_eq!(words, TEST_U64DATA_LE);
+
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::be(&mut data);
+        let words = [reader.read_u64().unwrap()];
+        assert_eq!(words, TEST_U64DATA_BE);
+
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Little);
+        let words = [reader.read_u64().unwrap()];
+        assert_eq!(words, TEST_U64DATA_LE);
+
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Big);
+        let words = [reader.read_u64().unwrap()];
+        assert_eq!(words, TEST_U64DATA_BE);
+    }
+
+    #[test]
+    fn test_bool() {
+        let mut writer = ByteOrdered::be(Vec::new());
+        writer.write_bool(true).unwrap();
+        writer.write_bool(false).unwrap();
+        let mut data = writer.into_inner();
+        assert_eq!(data, [1, 0]);
+        data.push(0x80);
+
+        let mut reader = ByteOrdered::le(&data[..]);
+        assert!(reader.read_bool().unwrap());
+        assert!(!reader.read_bool().unwrap());
+        assert!(reader.read_bool().unwrap());
+        assert_eq!(
+            reader.read_bool().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+
+        let mut reader = ByteOrdered::le(&data[..]);
+        assert!(reader.read_bool_strict().unwrap());
+        assert!(!reader.read_bool_strict().unwrap());
+        let e = reader.read_bool_strict().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        assert_eq!(e.to_string(), "invalid boolean byte 0x80");
+    }
+
+    #[test]
+    fn test_write_u64() {
+        let mut writer = ByteOrdered::le(Vec::new());
+        for v in TEST_U64DATA_LE {
+            writer.write_u64(*v).unwrap();
+        }
+        assert_eq!(&*writer.into_inner(), TEST_BYTES);
+
+        let mut writer = ByteOrdered::be(Vec::new());
+        for v in TEST_U64DATA_BE {
+            writer.write_u64(*v).unwrap();
+        }
+        assert_eq!(&*writer.into_inner(), TEST_BYTES);
+
+        let mut writer = ByteOrdered::runtime(Vec::new(), Endianness::Little);
+        for v in TEST_U64DATA_LE {
+            writer.write_u64(*v).unwrap();
+        }
+        assert_eq!(&*writer.into_inner(), TEST_BYTES);
 
         let mut writer = ByteOrdered::runtime(Vec::new(), Endianness::Big);
         for v in TEST_U64DATA_BE {
             writer.write_u64(*v).unwrap();
         }
-        assert_eq!(&*writer.into_inner(), TEST_BYTES);
+        assert_eq!(&*writer.into_inner(), TEST_BYTES);
+    }
+
+    #[test]
+    fn test_read_vec() {
+        let mut writer = ByteOrdered::le(Vec::new());
+        writer.write_u32_into(&[1, 2, 3]).unwrap();
+        writer.write_f64_into(&[0.25, -8.]).unwrap();
+        writer.write_i16(-2).unwrap();
+        let data = writer.into_inner();
+
+        let mut reader = ByteOrdered::le(&data[..]);
+        let values = reader.read_u32_vec(3).unwrap();
+        assert_eq!(values, [1, 2, 3]);
+        assert_eq!(values.capacity(), 3);
+        assert_eq!(reader.read_f64_vec(2).unwrap(), [0.25, -8.]);
+        assert_eq!(reader.read_u64_vec(0).unwrap(), []);
+        let err = reader.read_i16_vec(2).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+        let mut reader = ByteOrdered::be(&[0x00, 0x00, 0x00, 0x05, 0xFF][..]);
+        assert_eq!(reader.read_i32_vec(1).unwrap(), [5]);
+    }
+
+    #[test]
+    fn test_write_into() {
+        let values: Vec<u32> = (0..1000).collect();
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Counting(Vec::new(), 0), e);
+            writer.write_u32_into(&values).unwrap();
+            writer.write_f32_into(&[1.5]).unwrap();
+            writer.write_i64_into(&[-3, 3]).unwrap();
+            let Counting(data, calls) = writer.into_inner();
+            assert_eq!(calls, 3);
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let mut read_values = vec![0; 1000];
+            reader.read_u32_into(&mut read_values).unwrap();
+            assert_eq!(read_values, values);
+            assert_eq!(reader.read_f32().unwrap(), 1.5);
+            assert_eq!(reader.read_i64().unwrap(), -3);
+            assert_eq!(reader.read_i64().unwrap(), 3);
+        }
+    }
+
+    /// the test bytes as two u32s in little endian
+    static TEST_U32DATA_LE: &[u32] = &[0x7856_3412, 0x8765_4321];
+    /// the test bytes as two u32s in big endian
+    static TEST_U32DATA_BE: &[u32] = &[0x1234_5678, 0x2143_6587];
+
+    #[test]
+    fn test_read_u32_into() {
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::le(&mut data);
+        let mut words = [0; 2];
+        reader.read_u32_into(&mut words).unwrap();
+        assert_eq!(words, TEST_U32DATA_LE);
+
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::be(&mut data);
+        let mut words = [0; 2];
+        reader.read_u32_into(&mut words).unwrap();
+        assert_eq!(words, TEST_U32DATA_BE);
+
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Little);
+        let mut words = [0; 2];
+        reader.read_u32_into(&mut words).unwrap();
+        assert_eq!(words, TEST_U32DATA_LE);
+
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Big);
+        let mut words = [0; 2];
+        reader.read_u32_into(&mut words).unwrap();
+        assert_eq!(words, TEST_U32DATA_BE);
+    }
+
+    #[test]
+    fn test_read_u32_and_set_endianness() {
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Little);
+        let v1 = reader.read_u32().unwrap();
+        assert_eq!(v1, TEST_U32DATA_LE[0]);
+
+        // change to big endian
+        reader.set_endianness(Endianness::Big);
+        let v2 = reader.read_u32().unwrap();
+        assert_eq!(v2, TEST_U32DATA_BE[1]);
+    }
+
+    #[test]
+    fn test_write_from_narrower() {
+        let mut writer = ByteOrdered::runtime(Vec::new(), Endianness::Little);
+        writer.write_u16_from(5u8).unwrap();
+        writer.write_i16_from(-1i8).unwrap();
+        writer.write_i32_from(0xFFFFu16).unwrap();
+        writer.write_u64_from(7u32).unwrap();
+        writer.write_i128_from(-2i64).unwrap();
+        writer.write_f64_from(0.5f32).unwrap();
+        // exact types still work
+        writer.write_u32_from(9u32).unwrap();
+
+        let data = writer.into_inner();
+        let mut reader = ByteOrdered::le(&data[..]);
+        assert_eq!(reader.read_u16().unwrap(), 5);
+        assert_eq!(reader.read_i16().unwrap(), -1);
+        assert_eq!(reader.read_i32().unwrap(), 0xFFFF);
+        assert_eq!(reader.read_u64().unwrap(), 7);
+        assert_eq!(reader.read_i128().unwrap(), -2);
+        assert_eq!(reader.read_f64().unwrap(), 0.5);
+        assert_eq!(reader.read_u32().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_expect() {
+        let mut reader = ByteOrdered::runtime(TEST_BYTES, Endianness::Big);
+        reader.expect_bytes(&TEST_BYTES[..2]).unwrap();
+        reader.expect_u16(0x5678).unwrap();
+        reader.expect_i32(0x2143_6587).unwrap();
+
+        let data = [TEST_BYTES, &[0x08]].concat();
+        let mut reader = ByteOrdered::le(&data[..]);
+        reader.expect_u64(TEST_U64DATA_LE[0]).unwrap();
+        let e = reader.expect_u8(0xFF).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        assert_eq!(e.to_string(), "unexpected u8: expected 0xff, found 0x8");
+
+        let mut reader = ByteOrdered::le(TEST_BYTES);
+        let e = reader.expect_bytes(&[0x12, 0x34, 0x00]).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        // running out of data is not a mismatch
+        let e = reader.expect_u128(0).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_take_records() {
+        let mut reader = ByteOrdered::be(TEST_BYTES);
+        let records: Vec<_> = reader
+            .take_records(3, |r| r.read_u16())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(records, vec![0x1234, 0x5678, 0x2143]);
+        assert_eq!(reader.read_u16().unwrap(), 0x6587);
+
+        // stops after the first error
+        let mut reader = ByteOrdered::le(TEST_BYTES);
+        let mut records = reader.take_records(5, |r| r.read_u32());
+        assert_eq!(records.next().unwrap().unwrap(), 0x7856_3412);
+        assert_eq!(records.next().unwrap().unwrap(), 0x8765_4321);
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        assert!(records.next().is_none());
+
+        let mut reader = ByteOrdered::le(TEST_BYTES);
+        assert_eq!(reader.take_records(0, |r| r.read_u8()).count(), 0);
+    }
+
+    #[test]
+    fn test_read_bytes_capped() {
+        let mut reader = ByteOrdered::le(TEST_BYTES);
+        assert_eq!(reader.read_bytes_capped(2, 2).unwrap(), &TEST_BYTES[..2]);
+        assert_eq!(reader.read_bytes_capped(0, 0).unwrap(), b"");
+
+        // a huge length is rejected before allocating anything
+        let e = reader.read_bytes_capped(usize::MAX, 64).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        // and nothing was consumed
+        assert_eq!(reader.read_bytes_capped(6, 64).unwrap(), &TEST_BYTES[2..]);
+
+        let e = reader.read_bytes_capped(1, 64).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_read_as_newtype() {
+        #[derive(Debug, PartialEq)]
+        struct Offset(u32);
+
+        impl From<u32> for Offset {
+            fn from(v: u32) -> Self {
+                Offset(v)
+            }
+        }
+
+        let mut reader = ByteOrdered::runtime(TEST_BYTES, Endianness::Little);
+        let offset: Offset = reader.read_u32_as().unwrap();
+        assert_eq!(offset, Offset(0x7856_3412));
+        // widening through a standard conversion also works
+        assert_eq!(reader.read_u16_as::<u64>().unwrap(), 0x4321);
+        assert_eq!(reader.read_i8_as::<i32>().unwrap(), 0x65);
+    }
+
+    #[test]
+    fn test_endianness_name() {
+        assert_eq!(ByteOrdered::le(TEST_BYTES).endianness_name(), "little");
+        assert_eq!(ByteOrdered::be(TEST_BYTES).endianness_name(), "big");
+        let reader = ByteOrdered::runtime(TEST_BYTES, Endianness::Little);
+        assert_eq!(reader.endianness_name(), "little");
+        let reader = reader.into_opposite();
+        assert_eq!(reader.endianness_name(), "big");
+
+        let native = ByteOrdered::native(TEST_BYTES);
+        let expected = if cfg!(target_endian = "little") {
+            "little"
+        } else {
+            "big"
+        };
+        assert_eq!(native.endianness_name(), expected);
+        let native = ByteOrdered::runtime(TEST_BYTES, Endianness::native());
+        assert_eq!(native.endianness_name(), expected);
+    }
+
+    #[test]
+    fn test_read_array_2d() {
+        let data: Vec<u8> = (0..24).collect();
+        let mut reader = ByteOrdered::runtime(&data[..], Endianness::Big);
+        let grid: [[u32; 3]; 2] = reader.read_u32_array_2d().unwrap();
+        assert_eq!(
+            grid,
+            [
+                [0x0001_0203, 0x0405_0607, 0x0809_0A0B],
+                [0x0C0D_0E0F, 0x1011_1213, 0x1415_1617],
+            ]
+        );
+
+        let mut reader = ByteOrdered::le(&data[..]);
+        let grid: [[u32; 3]; 2] = reader.read_u32_array_2d().unwrap();
+        assert_eq!(
+            grid,
+            [
+                [0x0302_0100, 0x0706_0504, 0x0B0A_0908],
+                [0x0F0E_0D0C, 0x1312_1110, 0x1716_1514],
+            ]
+        );
+
+        let mut reader = ByteOrdered::be(TEST_BYTES);
+        let words: [u16; 2] = reader.read_u16_array().unwrap();
+        assert_eq!(words, [0x1234, 0x5678]);
+        let e = reader.read_u16_array_2d::<2, 2>().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_into_inner_buffered() {
+        let mut writer = ByteOrdered::le(BufWriter::new(Vec::new()));
+        writer.write_u32(0x0403_0201).unwrap();
+        assert_eq!(writer.into_inner_buffered().unwrap(), [1, 2, 3, 4]);
+
+        /// A writer which refuses all data.
+        struct Refusing;
+
+        impl Write for Refusing {
+            fn write(&mut self, _: &[u8]) -> IoResult<usize> {
+                Err(IoError::new(ErrorKind::BrokenPipe, "closed"))
+            }
+
+            fn flush(&mut self) -> IoResult<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer = ByteOrdered::be(BufWriter::new(Refusing));
+        writer.write_u16(0x0102).unwrap();
+        let e = writer.into_inner_buffered().err().unwrap();
+        assert_eq!(e.error().kind(), ErrorKind::BrokenPipe);
+        // the buffered writer and its pending data are recovered
+        assert_eq!(e.into_inner().buffer(), &[0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_with_write_buffer() {
+        let mut unbuffered = ByteOrdered::be(Counting(Vec::new(), 0));
+        let mut buffered = ByteOrdered::be(Counting(Vec::new(), 0)).with_write_buffer(16);
+        for i in 0..10 {
+            unbuffered.write_u32(i).unwrap();
+            buffered.write_u32(i).unwrap();
+        }
+        buffered.flush().unwrap();
+        let unbuffered = unbuffered.into_inner();
+        let buffered = buffered.into_inner_buffered().ok().unwrap();
+        assert_eq!(buffered.0, unbuffered.0);
+        assert_eq!(unbuffered.1, 10);
+        assert_eq!(buffered.1, 3);
+    }
+
+    #[test]
+    fn test_read_len_usize() {
+        let mut reader = ByteOrdered::be(TEST_BYTES);
+        assert_eq!(reader.read_len_usize(LenWidth::U8).unwrap(), 0x12);
+        assert_eq!(reader.read_len_usize(LenWidth::U16).unwrap(), 0x3456);
+        let mut reader = ByteOrdered::le(TEST_BYTES);
+        assert_eq!(reader.read_len_usize(LenWidth::U32).unwrap(), 0x7856_3412);
+
+        // a length beyond u32::MAX is rejected if the target type is 32-bit wide
+        let len = u64::from(u32::MAX) + 1;
+        let e = checked_len::<u32>(len).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        assert_eq!(checked_len::<u32>(len - 1).unwrap(), u32::MAX);
+
+        let data = len.to_be_bytes();
+        let mut reader = ByteOrdered::be(&data[..]);
+        let result = reader.read_len_usize(LenWidth::U64);
+        if cfg!(target_pointer_width = "64") {
+            assert_eq!(result.unwrap() as u64, len);
+        } else {
+            assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+        }
     }
 
-    /// the test bytes as two u32s in little endian
-    static TEST_U32DATA_LE: &'static [u32] = &[0x7856_3412, 0x8765_4321];
-    /// the test bytes as two u32s in big endian
-    static TEST_U32DATA_BE: &'static [u32] = &[0x1234_5678, 0x2143_6587];
+    #[test]
+    fn test_read_count() {
+        let mut reader = ByteOrdered::be(TEST_BYTES);
+        assert_eq!(reader.read_count(LenWidth::U8, 0x12).unwrap(), 0x12);
+        let e = reader.read_count(LenWidth::U16, 0x3455).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+
+        let mut reader = ByteOrdered::le(TEST_BYTES);
+        assert_eq!(
+            reader.read_count(LenWidth::U32, usize::MAX).unwrap(),
+            0x7856_3412
+        );
+
+        // a count beyond the range of usize is rejected as well
+        let data = u64::MAX.to_be_bytes();
+        let mut reader = ByteOrdered::be(&data[..]);
+        let result = reader.read_count(LenWidth::U64, usize::MAX);
+        if cfg!(target_pointer_width = "64") {
+            assert_eq!(result.unwrap() as u64, u64::MAX);
+        } else {
+            assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+        }
+        let mut reader = ByteOrdered::be(&data[..]);
+        let e = reader.read_count(LenWidth::U64, 1 << 20).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+    }
 
     #[test]
-    fn test_read_u32_into() {
-        let mut data = TEST_BYTES;
-        let mut reader = ByteOrdered::le(&mut data);
+    fn test_endianness_tag() {
+        let mut writer = ByteOrdered::le(Vec::new());
+        writer.write_endianness_tag(Endianness::Big).unwrap();
+        writer.write_endianness_tag(Endianness::Little).unwrap();
+        let data = writer.into_inner();
+        assert_eq!(data, [1, 0]);
+
+        let mut reader = ByteOrdered::be(&data[..]);
+        assert_eq!(reader.read_endianness_tag().unwrap(), Endianness::Big);
+        assert_eq!(reader.read_endianness_tag().unwrap(), Endianness::Little);
+
+        let mut reader = ByteOrdered::be(&[2][..]);
+        let e = reader.read_endianness_tag().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        assert_eq!(e.to_string(), "invalid byte order tag 2");
+    }
+
+    #[test]
+    fn test_typed_iterators() {
+        let values: Vec<u64> = ByteOrdered::le(TEST_BYTES)
+            .u64s()
+            .collect::<IoResult<_>>()
+            .unwrap();
+        assert_eq!(values, TEST_U64DATA_LE);
+
+        let values: Vec<u32> = ByteOrdered::runtime(TEST_BYTES, Endianness::Big)
+            .u32s()
+            .collect::<IoResult<_>>()
+            .unwrap();
+        assert_eq!(values, TEST_U32DATA_BE);
+
+        // a trailing partial value is an error, and ends the iteration
+        let mut iter = ByteOrdered::be(&TEST_BYTES[..7]).u16s();
+        assert_eq!(iter.next().unwrap().unwrap(), 0x1234);
+        assert_eq!(iter.next().unwrap().unwrap(), 0x5678);
+        assert_eq!(iter.next().unwrap().unwrap(), 0x2143);
+        let e = iter.next().unwrap().unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        assert!(iter.next().is_none());
+
+        // interrupted reads are retried
+        let reader = ScriptedReader::new(vec![
+            Ok(vec![0x00, 0x00]),
+            Err(IoError::from(ErrorKind::Interrupted)),
+            Ok(vec![0x80, 0x3F]),
+        ]);
+        let values: Vec<f32> = ByteOrdered::le(reader)
+            .f32s()
+            .collect::<IoResult<_>>()
+            .unwrap();
+        assert_eq!(values, vec![1.0]);
+
+        assert_eq!(ByteOrdered::be(&[][..]).i128s().count(), 0);
+    }
+
+    /// Creates a reader which yields one byte at a time,
+    /// with an interruption before each byte.
+    fn interrupting_reader(data: &[u8]) -> ScriptedReader {
+        ScriptedReader::new(
+            data.iter()
+                .flat_map(|&b| vec![Err(IoError::from(ErrorKind::Interrupted)), Ok(vec![b])])
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_interrupted_reads_are_retried() {
+        let data = [TEST_BYTES, TEST_BYTES, TEST_BYTES, TEST_BYTES].concat();
+
+        let mut reader = ByteOrdered::le(interrupting_reader(&data));
+        assert_eq!(reader.read_u64().unwrap(), TEST_U64DATA_LE[0]);
         let mut words = [0; 2];
         reader.read_u32_into(&mut words).unwrap();
         assert_eq!(words, TEST_U32DATA_LE);
+        reader.expect_bytes(&TEST_BYTES[..2]).unwrap();
+        assert_eq!(reader.read_u16().unwrap(), 0x7856);
+        assert_eq!(reader.read_u8().unwrap(), 0x21);
 
-        let mut data = TEST_BYTES;
-        let mut reader = ByteOrdered::be(&mut data);
+        let mut reader = ByteOrdered::runtime(interrupting_reader(&data), Endianness::Big);
+        assert_eq!(reader.read_u64().unwrap(), TEST_U64DATA_BE[0]);
         let mut words = [0; 2];
         reader.read_u32_into(&mut words).unwrap();
         assert_eq!(words, TEST_U32DATA_BE);
+        let mut words = [MaybeUninit::uninit(); 2];
+        let words = reader.read_u32_into_uninit(&mut words).unwrap();
+        assert_eq!(words, TEST_U32DATA_BE);
+        let words: Vec<_> = reader.u32s().collect::<IoResult<_>>().unwrap();
+        assert_eq!(words, TEST_U32DATA_BE);
 
-        let mut data = TEST_BYTES;
-        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Little);
+        // the runtime byte order falls back to per-element reads
+        // in the `Endian` trait's provided methods
+        let mut reader = interrupting_reader(&data);
         let mut words = [0; 2];
+        Endianness::Big
+            .read_u32_into(&mut reader, &mut words)
+            .unwrap();
+        assert_eq!(words, TEST_U32DATA_BE);
+    }
+
+    #[test]
+    fn test_complex_f32() {
+        let samples = [(1.5, -0.25), (0., 3.), (-8., 0.125)];
+        let le: &[u8] = &[
+            0x00, 0x00, 0xC0, 0x3F, 0x00, 0x00, 0x80, 0xBE, //
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x40, //
+            0x00, 0x00, 0x00, 0xC1, 0x00, 0x00, 0x00, 0x3E,
+        ];
+        let be: Vec<u8> = le.chunks(4).flat_map(|c| c.iter().rev().cloned()).collect();
+
+        for &(e, data) in &[(Endianness::Little, le), (Endianness::Big, &be[..])] {
+            let mut reader = ByteOrdered::runtime(data, e);
+            let mut values = [(0., 0.); 3];
+            reader.read_complex_f32_into(&mut values).unwrap();
+            assert_eq!(values, samples);
+            assert!(reader.read_complex_f32().is_err());
+
+            let mut reader = ByteOrdered::runtime(data, e);
+            assert_eq!(reader.read_complex_f32().unwrap(), samples[0]);
+
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_complex_f32_into(&samples).unwrap();
+            assert_eq!(writer.into_inner(), data);
+        }
+    }
+
+    #[test]
+    fn test_seek_relative_keeps_buffer() {
+        let mut reader = ByteOrdered::le(Cursor::new(TEST_BYTES)).into_buffered_reader();
+        assert_eq!(reader.read_u16().unwrap(), 0x3412);
+        assert_eq!(reader.inner_mut().buffer().len(), 6);
+
+        reader.seek_relative(2).unwrap();
+        assert_eq!(reader.inner_mut().buffer().len(), 4);
+        assert_eq!(reader.read_u16().unwrap(), 0x4321);
+
+        reader.seek_relative(-4).unwrap();
+        assert_eq!(reader.inner_mut().buffer().len(), 6);
+        assert_eq!(reader.read_u32().unwrap(), 0x43217856);
+        // the whole input was read into the buffer at once
+        assert_eq!(reader.inner_mut().get_ref().position(), 8);
+    }
+
+    #[test]
+    fn test_number_format() {
+        let samples: &[(NumberFormat, u64, &[u8])] = &[
+            (
+                NumberFormat::new(4, Endianness::Little),
+                0x1234_5678,
+                &[0x78, 0x56, 0x34, 0x12],
+            ),
+            (
+                NumberFormat::new(4, Endianness::Big),
+                0x1234_5678,
+                &[0x12, 0x34, 0x56, 0x78],
+            ),
+            (
+                NumberFormat::new(8, Endianness::Little),
+                0x0102_0304_0506_0708,
+                &[8, 7, 6, 5, 4, 3, 2, 1],
+            ),
+            (
+                NumberFormat::new(8, Endianness::Big),
+                0x0102_0304_0506_0708,
+                &[1, 2, 3, 4, 5, 6, 7, 8],
+            ),
+        ];
+        for &(format, value, bytes) in samples {
+            // the wrapper's own byte order is irrelevant
+            let mut reader = ByteOrdered::le(bytes);
+            assert_eq!(reader.read_number(format).unwrap(), value);
+            let mut writer = ByteOrdered::be(Vec::new());
+            writer.write_number(format, value).unwrap();
+            assert_eq!(writer.into_inner(), bytes);
+        }
+
+        let mut reader = ByteOrdered::le(TEST_BYTES);
+        for &width in &[0, 9] {
+            let format = NumberFormat::new(width, Endianness::Big);
+            let e = reader.read_number(format).unwrap_err();
+            assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        }
+        let mut writer = ByteOrdered::le(Vec::new());
+        let format = NumberFormat::new(2, Endianness::Little);
+        let e = writer.write_number(format, 0x1_0000).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        assert!(writer.into_inner().is_empty());
+    }
+
+    #[test]
+    fn test_write_narrowing() {
+        let mut writer = ByteOrdered::be(Vec::new());
+        writer.write_u16_from_u32(0xFFFF).unwrap();
+        writer.write_u8_from_u32(0x12).unwrap();
+        writer.write_i16_from_i64(-2).unwrap();
+        writer.write_u32_from_u64(0x0102_0304).unwrap();
+        assert_eq!(
+            writer.inner_mut()[..],
+            [0xFF, 0xFF, 0x12, 0xFF, 0xFE, 0x01, 0x02, 0x03, 0x04]
+        );
+
+        let e = writer.write_u16_from_u32(0x1_0000).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        let e = writer.write_i8_from_i32(-129).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        let e = writer.write_u32_from_u64(u64::MAX).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        // nothing was written on failure
+        assert_eq!(writer.into_inner().len(), 9);
+    }
+
+    #[test]
+    fn test_ensure_buffered() {
+        let data: Vec<u8> = (0..32).collect();
+        let inner = BufReader::with_capacity(16, Cursor::new(data));
+        let mut reader = ByteOrdered::le(inner);
+        assert_eq!(reader.capacity(), 16);
+        assert!(reader.buffer().is_empty());
+
+        let mut words = [0; 3];
         reader.read_u32_into(&mut words).unwrap();
+        assert_eq!(reader.buffer(), &[12, 13, 14, 15]);
+
+        let buf = reader.ensure_buffered(8).unwrap();
+        assert!(buf.len() >= 8);
+        let peeked = Endianness::Little.read_u64(&buf[..8]).unwrap();
+        assert_eq!(peeked, 0x1312_1110_0F0E_0D0C);
+        // nothing was consumed
+        assert_eq!(reader.read_u64().unwrap(), peeked);
+
+        // near the end of the stream, fewer bytes are available
+        reader.seek_relative(4).unwrap();
+        assert_eq!(
+            reader.ensure_buffered(8).unwrap(),
+            &[24, 25, 26, 27, 28, 29, 30, 31]
+        );
+        reader.read_u32().unwrap();
+        assert_eq!(reader.ensure_buffered(8).unwrap(), &[28, 29, 30, 31]);
+
+        let e = reader.ensure_buffered(17).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let reader = ByteOrdered::runtime(TEST_BYTES, Endianness::Big);
+        let (mut head, mut tail) = reader.split_at(2).unwrap();
+        assert_eq!(tail.read_u16().unwrap(), 0x5678);
+        assert_eq!(head.read_u16().unwrap(), 0x1234);
+        assert!(head.read_u8().is_err());
+        assert_eq!(tail.read_u32().unwrap(), 0x2143_6587);
+
+        let reader = ByteOrdered::le(TEST_BYTES);
+        let (head, tail) = reader.split_at(8).unwrap();
+        assert_eq!(head.into_inner(), TEST_BYTES);
+        assert!(tail.into_inner().is_empty());
+
+        let e = ByteOrdered::le(TEST_BYTES).split_at(9).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_read_f64_with() {
+        let bits = 0x4009_21FB_5444_2D18_u64;
+        let be = bits.to_be_bytes();
+        let le = bits.to_le_bytes();
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut reader = ByteOrdered::runtime(&be[..], e);
+            assert_eq!(
+                reader.read_f64_with(Endianness::Big).unwrap(),
+                ::std::f64::consts::PI
+            );
+            let mut reader = ByteOrdered::runtime(&le[..], e);
+            assert_eq!(
+                reader.read_f64_with(Endianness::Little).unwrap(),
+                ::std::f64::consts::PI
+            );
+        }
+        // the override does not change the wrapper's order
+        let mut data = le.to_vec();
+        data.extend_from_slice(&[0x12, 0x34]);
+        let mut reader = ByteOrdered::be(&data[..]);
+        assert_eq!(
+            reader.read_f64_with(Endianness::Little).unwrap(),
+            ::std::f64::consts::PI
+        );
+        assert_eq!(reader.read_u16().unwrap(), 0x1234);
+    }
+
+    /// A reader and writer which records its vectored calls.
+    #[derive(Default)]
+    struct Vectored {
+        data: Vec<u8>,
+        vectored_calls: usize,
+    }
+
+    impl Read for Vectored {
+        fn read(&mut self, _: &mut [u8]) -> IoResult<usize> {
+            panic!("read_vectored should have been forwarded")
+        }
+
+        fn read_vectored(&mut self, bufs: &mut [::std::io::IoSliceMut]) -> IoResult<usize> {
+            self.vectored_calls += 1;
+            let mut n = 0;
+            for buf in bufs {
+                let len = buf.len().min(self.data.len() - n);
+                buf[..len].copy_from_slice(&self.data[n..n + len]);
+                n += len;
+            }
+            self.data.drain(..n);
+            Ok(n)
+        }
+
+        #[cfg(feature = "can_vector")]
+        fn is_read_vectored(&self) -> bool {
+            true
+        }
+    }
+
+    impl Write for Vectored {
+        fn write(&mut self, _: &[u8]) -> IoResult<usize> {
+            panic!("write_vectored should have been forwarded")
+        }
+
+        fn write_vectored(&mut self, bufs: &[::std::io::IoSlice]) -> IoResult<usize> {
+            self.vectored_calls += 1;
+            let mut n = 0;
+            for buf in bufs {
+                self.data.extend_from_slice(buf);
+                n += buf.len();
+            }
+            Ok(n)
+        }
+
+        #[cfg(feature = "can_vector")]
+        fn is_write_vectored(&self) -> bool {
+            true
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_vectored() {
+        use std::io::{IoSlice, IoSliceMut};
+
+        let mut writer = ByteOrdered::be(Vectored::default());
+        let n = writer
+            .write_vectored(&[IoSlice::new(&[1, 2]), IoSlice::new(&[3, 4, 5])])
+            .unwrap();
+        assert_eq!(n, 5);
+        assert_eq!(writer.inner_mut().vectored_calls, 1);
+
+        let mut reader = writer;
+        let (mut a, mut b) = ([0; 3], [0; 3]);
+        let n = reader
+            .read_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)])
+            .unwrap();
+        assert_eq!(n, 5);
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(b, [4, 5, 0]);
+        assert_eq!(reader.inner_mut().vectored_calls, 2);
+    }
+
+    #[cfg(feature = "can_vector")]
+    #[test]
+    fn test_is_vectored() {
+        let wrapped = ByteOrdered::le(Vectored::default());
+        assert!(wrapped.is_read_vectored());
+        assert!(wrapped.is_write_vectored());
+    }
+
+    #[cfg(feature = "read_buf")]
+    #[test]
+    fn test_read_buf() {
+        use std::io::BorrowedBuf;
+
+        /// A reader which only implements `read_buf`,
+        /// so that falling back to `read` would be noticed.
+        struct CursorOnly<'a>(&'a [u8]);
+
+        impl<'a> Read for CursorOnly<'a> {
+            fn read(&mut self, _: &mut [u8]) -> IoResult<usize> {
+                panic!("read_buf should have been forwarded")
+            }
+
+            fn read_buf(&mut self, mut cursor: ::std::io::BorrowedCursor) -> IoResult<()> {
+                let n = self.0.len().min(cursor.capacity());
+                cursor.append(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(())
+            }
+        }
+
+        let mut reader = ByteOrdered::be(CursorOnly(&[1, 2, 3, 4, 5]));
+        let mut memory = [MaybeUninit::uninit(); 4];
+        let mut buf = BorrowedBuf::from(&mut memory[..]);
+        reader.read_buf(buf.unfilled()).unwrap();
+        assert_eq!(buf.filled(), &[1, 2, 3, 4]);
+
+        buf.clear();
+        reader.read_buf(buf.unfilled()).unwrap();
+        assert_eq!(buf.filled(), &[5]);
+    }
+
+    #[test]
+    fn test_24_bit() {
+        // a stereo frame of 24 bit PCM: a positive left sample,
+        // a negative right sample
+        let samples = [0x12_3456, -0x12_3456];
+        let le = [0x56, 0x34, 0x12, 0xAA, 0xCB, 0xED];
+        let be = [0x12, 0x34, 0x56, 0xED, 0xCB, 0xAA];
+        for &(e, data) in &[(Endianness::Little, le), (Endianness::Big, be)] {
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let mut frame = [0; 2];
+            reader.read_i24_into(&mut frame).unwrap();
+            assert_eq!(frame, samples);
+            assert!(reader.into_inner().is_empty());
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_i24().unwrap(), samples[0]);
+            assert_eq!(reader.read_u24().unwrap(), 0xED_CBAA);
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            let mut raw = [0; 2];
+            reader.read_u24_into(&mut raw).unwrap();
+            assert_eq!(raw, [0x12_3456, 0xED_CBAA]);
+
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_i24_into(&samples).unwrap();
+            writer.write_i24(samples[0]).unwrap();
+            writer.write_u24_into(&raw).unwrap();
+            writer.write_u24(0xED_CBAA).unwrap();
+            let written = writer.into_inner();
+            assert_eq!(&written[..6], &data[..]);
+            assert_eq!(&written[6..9], &data[..3]);
+            assert_eq!(&written[9..15], &data[..]);
+            assert_eq!(&written[15..], &data[3..]);
+
+            // not enough bytes for the last sample
+            let mut reader = ByteOrdered::runtime(&data[..5], e);
+            let err = reader.read_i24_into(&mut frame).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            let err = writer.write_i24_into(&[0, 0x80_0000]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            let err = writer.write_i24(-0x80_0001).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            let err = writer.write_u24(0x100_0000).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            writer.write_i24_into(&[-0x80_0000, 0x7F_FFFF]).unwrap();
+            assert_eq!(writer.into_inner().len(), 6);
+        }
+    }
+
+    #[test]
+    fn test_read_u32_while() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            for &v in &[1, 5, 20, 100, 3] {
+                writer.write_u32(v).unwrap();
+            }
+            let mut reader = ByteOrdered::runtime(Cursor::new(writer.into_inner()), e);
+            let values = reader.read_u32_while(|x| x <= 50).unwrap();
+            assert_eq!(values, vec![1, 5, 20]);
+            assert_eq!(reader.inner_mut().position(), 12);
+            assert_eq!(reader.read_u32().unwrap(), 100);
+
+            // a value failing the predicate right away consumes nothing
+            let values = reader.read_u32_while(|x| x > 50).unwrap();
+            assert!(values.is_empty());
+            assert_eq!(reader.inner_mut().position(), 16);
+
+            let err = reader.read_u32_while(|_| true).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        }
+    }
+
+    #[test]
+    fn test_read_strided() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            // records of a u32 and a u16, padded to 8 bytes
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            for i in 0..3 {
+                writer.write_u32(0x1000 + i).unwrap();
+                writer.write_u16(i as u16).unwrap();
+                writer.write_u16(0xDEAD).unwrap();
+            }
+            writer.write_u8(0x2A).unwrap();
+            let data = writer.into_inner();
+
+            let mut reader = ByteOrdered::runtime(Cursor::new(data.clone()), e);
+            let records = reader
+                .read_strided(3, 8, |r| Ok((r.read_u32()?, r.read_u16()?)))
+                .unwrap();
+            assert_eq!(records, vec![(0x1000, 0), (0x1001, 1), (0x1002, 2)]);
+            assert_eq!(reader.inner_mut().position(), 24);
+            assert_eq!(reader.read_u8().unwrap(), 0x2A);
+
+            // a record filling the whole stride needs no padding
+            let mut reader = ByteOrdered::runtime(Cursor::new(data.clone()), e);
+            let records = reader.read_strided(2, 8, |r| r.read_u64()).unwrap();
+            assert_eq!(records.len(), 2);
+            assert_eq!(reader.inner_mut().position(), 16);
+
+            // a record larger than the stride is rejected
+            let mut reader = ByteOrdered::runtime(Cursor::new(data), e);
+            let err = reader
+                .read_strided(2, 4, |r| Ok((r.read_u32()?, r.read_u16()?)))
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn test_read_exact_or_eof() {
+        /// A reader which yields one byte per call.
+        struct Trickle<'a>(&'a [u8]);
+
+        impl<'a> Read for Trickle<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+                let n = buf.len().min(self.0.len()).min(1);
+                buf[..n].copy_from_slice(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(n)
+            }
+        }
+
+        let mut reader = ByteOrdered::le(Trickle(&[1, 2, 3, 4, 5]));
+        let mut buf = [0; 2];
+        assert!(reader.read_exact_or_eof(&mut buf).unwrap());
+        assert_eq!(buf, [1, 2]);
+        assert!(reader.read_exact_or_eof(&mut buf).unwrap());
+        assert_eq!(buf, [3, 4]);
+        // only one byte left
+        let err = reader.read_exact_or_eof(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        // clean end of stream
+        assert!(!reader.read_exact_or_eof(&mut buf).unwrap());
+        assert!(reader.read_exact_or_eof(&mut []).unwrap());
+    }
+
+    #[test]
+    fn test_opt_sentinel() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_u64_opt_sentinel(Some(7), u64::MAX).unwrap();
+            writer.write_u64_opt_sentinel(None, u64::MAX).unwrap();
+            writer.write_u32_opt_sentinel(None, 0).unwrap();
+            writer.write_u16_opt_sentinel(Some(0xFFFF), 0).unwrap();
+            let err = writer.write_u32_opt_sentinel(Some(0), 0).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            let data = writer.into_inner();
+            assert_eq!(data.len(), 8 + 8 + 4 + 2);
+            assert_eq!(&data[8..16], &[0xFF; 8]);
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_u64_opt_sentinel(u64::MAX).unwrap(), Some(7));
+            assert_eq!(reader.read_u64_opt_sentinel(u64::MAX).unwrap(), None);
+            assert_eq!(reader.read_u32_opt_sentinel(0).unwrap(), None);
+            assert_eq!(reader.read_u16_opt_sentinel(0).unwrap(), Some(0xFFFF));
+            let err = reader.read_u16_opt_sentinel(0).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        }
+    }
+
+    #[test]
+    fn test_optional() {
+        for &e in &[Endianness::Little, Endianness::Big] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_optional_u16(Some(0xABCD)).unwrap();
+            writer.write_optional_u32(None).unwrap();
+            writer.write_optional_u32(Some(0)).unwrap();
+            writer.write_optional_u64(Some(u64::MAX)).unwrap();
+            writer.write_optional_u64(None).unwrap();
+            writer
+                .write_optional_with(Some(-1.5), |w, v| w.write_f32(v))
+                .unwrap();
+            let data = writer.into_inner();
+            assert_eq!(data.len(), 3 + 1 + 5 + 9 + 1 + 5);
+            assert_eq!(data[3..5], [0, 1]);
+
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_optional_u16().unwrap(), Some(0xABCD));
+            assert_eq!(reader.read_optional_u32().unwrap(), None);
+            assert_eq!(reader.read_optional_u32().unwrap(), Some(0));
+            assert_eq!(reader.read_optional_u64().unwrap(), Some(u64::MAX));
+            assert_eq!(reader.read_optional_u64().unwrap(), None);
+            let v = reader.read_optional_with(|r| r.read_f32()).unwrap();
+            assert_eq!(v, Some(-1.5));
+            assert!(reader.into_inner().is_empty());
+        }
+
+        // any nonzero flag means present
+        let mut reader = ByteOrdered::be(&[0x80, 0x00, 0x07][..]);
+        assert_eq!(reader.read_optional_u16().unwrap(), Some(7));
+    }
+
+    #[test]
+    fn test_read_u128_halves() {
+        let x = 0x0011_2233_4455_6677_8899_AABB_CCDD_EEFF_u128;
+        let data = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, //
+            0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+        ];
+        let mut reader = ByteOrdered::be(&data[..]);
+        assert_eq!(reader.read_u128_halves(false).unwrap(), x);
+        let mut reader = ByteOrdered::be(&data[..]);
+        assert_eq!(
+            reader.read_u128_halves(true).unwrap(),
+            0x8899_AABB_CCDD_EEFF_0011_2233_4455_6677
+        );
+
+        let mut swapped = data;
+        swapped.reverse();
+        let mut reader = ByteOrdered::le(&swapped[..]);
+        assert_eq!(reader.read_u128_halves(true).unwrap(), x);
+        let mut reader = ByteOrdered::le(&swapped[..]);
+        assert_eq!(
+            reader.read_u128_halves(false).unwrap(),
+            0x8899_AABB_CCDD_EEFF_0011_2233_4455_6677
+        );
+
+        // the natural half order matches a plain 128 bit read
+        for &(e, low_first) in &[(Endianness::Big, false), (Endianness::Little, true)] {
+            let mut writer = ByteOrdered::runtime(Vec::new(), e);
+            writer.write_u128(x).unwrap();
+            let data = writer.into_inner();
+            let mut reader = ByteOrdered::runtime(&data[..], e);
+            assert_eq!(reader.read_u128_halves(low_first).unwrap(), x);
+        }
+    }
+
+    #[test]
+    fn test_sync_all() {
+        let path = ::std::env::temp_dir().join(format!(
+            "byteordered-test-sync-{}.bin",
+            ::std::process::id()
+        ));
+
+        let mut writer = ByteOrdered::be(File::create(&path).unwrap());
+        writer.write_u16(0x0102).unwrap();
+        writer.sync_all().unwrap();
+        assert_eq!(fs::read(&path).unwrap(), [0x01, 0x02]);
+
+        let mut writer = ByteOrdered::le(BufWriter::new(File::create(&path).unwrap()));
+        writer.write_u32(0x0102_0304).unwrap();
+        writer.sync_all().unwrap();
+        // the buffered data was written out
+        assert_eq!(fs::read(&path).unwrap(), [0x04, 0x03, 0x02, 0x01]);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_read_into_containers() {
+        let data: &[u8] = &[0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3];
+
+        let mut boxed = vec![0; 3].into_boxed_slice();
+        ByteOrdered::be(data).read_u32_into(&mut boxed).unwrap();
+        assert_eq!(&boxed[..], &[1, 2, 3]);
+
+        let mut vec = vec![0; 2];
+        ByteOrdered::be(data).read_u32_into(&mut vec).unwrap();
+        assert_eq!(vec, [1, 2]);
+
+        let mut array = [0; 3];
+        ByteOrdered::be(data).read_u32_into(&mut array).unwrap();
+        assert_eq!(array, [1, 2, 3]);
+
+        let mut buf = [0; 3];
+        ByteOrdered::be(data).read_u32_into(&mut buf[1..]).unwrap();
+        assert_eq!(buf, [0, 1, 2]);
+
+        let mut samples = vec![0; 2];
+        ByteOrdered::le(data).read_i24_into(&mut samples).unwrap();
+        assert_eq!(samples, [0, 1]);
+    }
+
+    #[test]
+    fn test_read_at() {
+        let reader = ByteOrdered::le(TEST_BYTES);
+        assert_eq!(reader.read_u16_at(0).unwrap(), 0x3412);
+        assert_eq!(reader.read_i16_at(6).unwrap(), -0x789B);
+        assert_eq!(reader.read_u32_at(1).unwrap(), 0x2178_5634);
+        assert_eq!(reader.read_u64_at(0).unwrap(), TEST_U64DATA_LE[0]);
+        let reader = ByteOrdered::be(TEST_BYTES);
+        assert_eq!(reader.read_u32_at(4).unwrap(), 0x2143_6587);
+        assert_eq!(reader.read_i32_at(4).unwrap(), 0x2143_6587);
+        assert_eq!(reader.read_i64_at(0).unwrap(), TEST_U64DATA_BE[0] as i64);
+        // the position is left untouched
+        assert_eq!(reader.into_inner(), TEST_BYTES);
+
+        let reader = ByteOrdered::be(TEST_BYTES);
+        for &offset in &[5, 8, 9, usize::MAX] {
+            let err = reader.read_u32_at(offset).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        }
+        assert_eq!(
+            reader.read_u64_at(1).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+
+        // 128 bit integers and floating point numbers
+        let mut writer = ByteOrdered::le(vec![0xAA]);
+        writer.write_f32(1.5).unwrap();
+        writer.write_f64(-0.25).unwrap();
+        writer.write_i128(-2).unwrap();
+        writer.write_u128(u128::MAX - 1).unwrap();
+        let data = writer.into_inner();
+        let reader = ByteOrdered::le(&data[..]);
+        assert_eq!(reader.read_f32_at(1).unwrap(), 1.5);
+        assert_eq!(reader.read_f64_at(5).unwrap(), -0.25);
+        assert_eq!(reader.read_i128_at(13).unwrap(), -2);
+        assert_eq!(reader.read_u128_at(29).unwrap(), u128::MAX - 1);
+        let errors = [
+            reader.read_f32_at(42).unwrap_err(),
+            reader.read_f64_at(38).unwrap_err(),
+            reader.read_i128_at(30).unwrap_err(),
+            reader.read_u128_at(usize::MAX).unwrap_err(),
+        ];
+        for err in &errors {
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_restore() {
+        let mut reader = ByteOrdered::be(Cursor::new(TEST_BYTES.to_vec()));
+        reader.read_u16().unwrap();
+        let cp = reader.checkpoint().unwrap();
+        assert_eq!(cp.position(), 2);
+        let first = reader.read_u32().unwrap();
+        reader.read_u8().unwrap();
+        reader.restore(cp).unwrap();
+        assert_eq!(reader.checkpoint().unwrap(), cp);
+        assert_eq!(reader.read_u32().unwrap(), first);
+        assert_eq!(reader.into_inner().position(), 6);
+    }
+
+    #[test]
+    fn test_clone_reader_is_independent() {
+        let mut reader = ByteOrdered::runtime(Cursor::new(TEST_BYTES.to_vec()), Endianness::Big);
+        assert_eq!(reader.read_u16().unwrap(), 0x1234);
+
+        let mut clone = reader.clone_reader();
+        assert_eq!(clone.endianness(), Endianness::Big);
+        assert_eq!(clone.read_u32().unwrap(), 0x5678_2143);
+        assert_eq!(clone.read_u16().unwrap(), 0x6587);
+
+        // the original reader is still right after the first u16
+        assert_eq!(reader.inner_mut().position(), 2);
+        assert_eq!(reader.read_u16().unwrap(), 0x5678);
+    }
+
+    /// A reader which replays a script of chunks and errors.
+    struct ScriptedReader(VecDeque<IoResult<Vec<u8>>>);
+
+    impl ScriptedReader {
+        fn new(script: Vec<IoResult<Vec<u8>>>) -> Self {
+            ScriptedReader(script.into_iter().collect())
+        }
+    }
+
+    impl Read for ScriptedReader {
+        fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+            match self.0.pop_front() {
+                None => Ok(0),
+                Some(Err(e)) => Err(e),
+                Some(Ok(mut chunk)) => {
+                    let n = chunk.len().min(buf.len());
+                    buf[..n].copy_from_slice(&chunk[..n]);
+                    if n < chunk.len() {
+                        self.0.push_front(Ok(chunk.split_off(n)));
+                    }
+                    Ok(n)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_u32_into_resumable() {
+        let would_block = || Err(IoError::new(ErrorKind::WouldBlock, "not yet"));
+        let interrupted = || Err(IoError::new(ErrorKind::Interrupted, "signal"));
+        let source = ScriptedReader::new(vec![
+            Ok(TEST_BYTES[..3].to_vec()),
+            interrupted(),
+            Ok(TEST_BYTES[3..4].to_vec()),
+            would_block(),
+            would_block(),
+            Ok(TEST_BYTES[4..].to_vec()),
+        ]);
+        let mut reader = ByteOrdered::be(source);
+        let mut state = ResumeState::default();
+        let mut words = [0; 2];
+
+        let progress = reader
+            .read_u32_into_resumable(&mut words, 0, &mut state)
+            .unwrap();
+        assert_eq!(progress, 1);
+        assert_eq!(words[0], TEST_U32DATA_BE[0]);
+
+        let progress = reader
+            .read_u32_into_resumable(&mut words, progress, &mut state)
+            .unwrap();
+        assert_eq!(progress, 1);
+        assert_eq!(state.pending(), 0);
+
+        let progress = reader
+            .read_u32_into_resumable(&mut words, progress, &mut state)
+            .unwrap();
+        assert_eq!(progress, 2);
+        assert_eq!(words, TEST_U32DATA_BE);
+
+        // nothing left to fill
+        let progress = reader
+            .read_u32_into_resumable(&mut words, 2, &mut state)
+            .unwrap();
+        assert_eq!(progress, 2);
+    }
+
+    #[test]
+    fn test_read_u32_into_resumable_mid_element() {
+        let would_block = || Err(IoError::new(ErrorKind::WouldBlock, "not yet"));
+        let source = ScriptedReader::new(vec![
+            Ok(TEST_BYTES[..6].to_vec()),
+            would_block(),
+            Ok(TEST_BYTES[6..7].to_vec()),
+            would_block(),
+            Ok(TEST_BYTES[7..].to_vec()),
+        ]);
+        let mut reader = ByteOrdered::le(source);
+        let mut state = ResumeState::default();
+        let mut words = [0; 2];
+
+        // blocks after 2 bytes of the second element
+        let progress = reader
+            .read_u32_into_resumable(&mut words, 0, &mut state)
+            .unwrap();
+        assert_eq!(progress, 1);
+        assert_eq!(state.pending(), 2);
+
+        let progress = reader
+            .read_u32_into_resumable(&mut words, progress, &mut state)
+            .unwrap();
+        assert_eq!(progress, 1);
+        assert_eq!(state.pending(), 3);
+
+        let progress = reader
+            .read_u32_into_resumable(&mut words, progress, &mut state)
+            .unwrap();
+        assert_eq!(progress, 2);
+        assert_eq!(state.pending(), 0);
+        assert_eq!(words, TEST_U32DATA_LE);
+    }
+
+    #[test]
+    fn test_read_u32_into_resumable_errors() {
+        let source = ScriptedReader::new(vec![Ok(TEST_BYTES[..6].to_vec())]);
+        let mut reader = ByteOrdered::le(source);
+        let mut state = ResumeState::default();
+        let mut words = [0; 2];
+        let e = reader
+            .read_u32_into_resumable(&mut words, 0, &mut state)
+            .unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        assert_eq!(words[0], TEST_U32DATA_LE[0]);
+
+        let source = ScriptedReader::new(vec![
+            Ok(TEST_BYTES[..1].to_vec()),
+            Err(IoError::new(ErrorKind::ConnectionReset, "reset")),
+        ]);
+        let mut reader = ByteOrdered::le(source);
+        let mut state = ResumeState::default();
+        let e = reader
+            .read_u32_into_resumable(&mut words, 0, &mut state)
+            .unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::ConnectionReset);
+    }
+
+    #[test]
+    fn test_read_within() {
+        // records fill the budget exactly
+        let mut reader = ByteOrdered::be(Cursor::new(TEST_BYTES.to_vec()));
+        let words = reader.read_within(8, |r| r.read_u16()).unwrap();
+        assert_eq!(words, vec![0x1234, 0x5678, 0x2143, 0x6587]);
+
+        // the budget ends before the data does
+        let mut reader = ByteOrdered::le(Cursor::new(TEST_BYTES.to_vec()));
+        let words = reader.read_within(4, |r| r.read_u16()).unwrap();
+        assert_eq!(words, vec![0x3412, 0x7856]);
+        assert_eq!(reader.read_u32().unwrap(), TEST_U32DATA_LE[1]);
+
+        // zero budget reads nothing
+        let mut reader = ByteOrdered::le(Cursor::new(TEST_BYTES.to_vec()));
+        let words = reader.read_within(0, |r| r.read_u16()).unwrap();
+        assert!(words.is_empty());
+    }
+
+    #[test]
+    fn test_read_within_overflow() {
+        let mut reader = ByteOrdered::be(Cursor::new(TEST_BYTES.to_vec()));
+        let e = reader
+            .read_within(5, |r| Ok((r.read_u8()?, r.read_u16()?)))
+            .unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+
+        // a decoder which does not consume anything is rejected
+        let mut reader = ByteOrdered::be(Cursor::new(TEST_BYTES.to_vec()));
+        let e = reader.read_within(5, |_| Ok(())).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_read_into_uninit() {
+        use std::mem::MaybeUninit;
+
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::le(&mut data);
+        let mut words = [MaybeUninit::<u32>::uninit(); 2];
+        let words = reader.read_u32_into_uninit(&mut words).unwrap();
         assert_eq!(words, TEST_U32DATA_LE);
 
         let mut data = TEST_BYTES;
         let mut reader = ByteOrdered::runtime(&mut data, Endianness::Big);
-        let mut words = [0; 2];
-        reader.read_u32_into(&mut words).unwrap();
+        let mut words = [MaybeUninit::<u32>::uninit(); 2];
+        let words = reader.read_u32_into_uninit(&mut words).unwrap();
         assert_eq!(words, TEST_U32DATA_BE);
+
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Big);
+        let mut words = [MaybeUninit::<u64>::uninit(); 1];
+        let words = reader.read_u64_into_uninit(&mut words).unwrap();
+        assert_eq!(words, TEST_U64DATA_BE);
+
+        let mut data: &[u8] = &[0x3F, 0xF0, 0, 0, 0, 0, 0, 0];
+        let mut reader = ByteOrdered::be(&mut data);
+        let mut values = [MaybeUninit::<f64>::uninit(); 1];
+        let values = reader.read_f64_into_uninit(&mut values).unwrap();
+        assert_eq!(values, [1.0]);
+
+        // not enough data
+        let mut data = TEST_BYTES;
+        let mut reader = ByteOrdered::le(&mut data);
+        let mut words = [MaybeUninit::<u16>::uninit(); 5];
+        assert!(reader.read_u16_into_uninit(&mut words).is_err());
     }
 
     #[test]
-    fn test_read_u32_and_set_endianness() {
-        let mut data = TEST_BYTES;
-        let mut reader = ByteOrdered::runtime(&mut data, Endianness::Little);
-        let v1 = reader.read_u32().unwrap();
-        assert_eq!(v1, TEST_U32DATA_LE[0]);
+    fn test_from_byteorder_marker() {
+        use byteorder::{BigEndian, LittleEndian, NativeEndian, NetworkEndian};
+        use FromByteOrder;
 
-        // change to big endian
-        reader.set_endianness(Endianness::Big);
-        let v2 = reader.read_u32().unwrap();
-        assert_eq!(v2, TEST_U32DATA_BE[1]);
+        fn read_first<BO: FromByteOrder>() -> u32 {
+            let mut reader: ByteOrdered<_, StaticEndianness<BO>> =
+                ByteOrdered::from_byteorder_marker(TEST_BYTES);
+            reader.read_u32().unwrap()
+        }
+
+        assert_eq!(read_first::<LittleEndian>(), TEST_U32DATA_LE[0]);
+        assert_eq!(read_first::<BigEndian>(), TEST_U32DATA_BE[0]);
+        assert_eq!(read_first::<NetworkEndian>(), TEST_U32DATA_BE[0]);
+        if cfg!(target_endian = "little") {
+            assert_eq!(read_first::<NativeEndian>(), TEST_U32DATA_LE[0]);
+        } else {
+            assert_eq!(read_first::<NativeEndian>(), TEST_U32DATA_BE[0]);
+        }
+
+        let reader =
+            ByteOrdered::<_, StaticEndianness<NetworkEndian>>::from_byteorder_marker(TEST_BYTES);
+        assert_eq!(reader.endianness(), StaticEndianness::<BigEndian>::new());
+    }
+
+    #[test]
+    fn test_transfer_duplex() {
+        let mut data = TEST_BYTES[..4].to_vec();
+        data.extend_from_slice(&[0; 6]);
+        let mut stream = ByteOrdered::runtime(Cursor::new(data), Endianness::Little);
+        let v = stream
+            .transfer(ByteOrdered::read_u32, ByteOrdered::write_u32)
+            .unwrap();
+        assert_eq!(v, TEST_U32DATA_LE[0]);
+
+        // switch byte order midway: the value is echoed in the new order
+        stream.set_endianness(Endianness::Big);
+        stream.inner_mut().set_position(0);
+        let v = stream
+            .transfer(ByteOrdered::read_u16, ByteOrdered::write_u16)
+            .unwrap();
+        assert_eq!(v, 0x1234);
+
+        let data = stream.into_inner().into_inner();
+        assert_eq!(&data[..4], &[0x12, 0x34, 0x12, 0x34]);
+        assert_eq!(&data[4..8], &TEST_BYTES[..4]);
     }
 }
@@ -0,0 +1,42 @@
+extern crate byteordered;
+extern crate flate2;
+
+use byteordered::{ByteOrdered, Endianness};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::io::{BufRead, Write};
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn test_typed_reads_over_decoder() {
+    for &e in &[Endianness::Little, Endianness::Big] {
+        let mut wt = ByteOrdered::runtime(Vec::new(), e);
+        for i in 0..1000u32 {
+            wt.write_u32(i * 3).unwrap();
+            wt.write_f64(f64::from(i) / 2.).unwrap();
+        }
+        wt.write_all(b"end\n").unwrap();
+        let compressed = compress(&wt.into_inner());
+
+        let mut rd =
+            ByteOrdered::runtime(DeflateDecoder::new(&compressed[..]), e).into_buffered_reader();
+        for i in 0..1000u32 {
+            assert_eq!(rd.read_u32().unwrap(), i * 3);
+            assert_eq!(rd.read_f64().unwrap(), f64::from(i) / 2.);
+        }
+        let mut line = String::new();
+        rd.read_line(&mut line).unwrap();
+        assert_eq!(line, "end\n");
+        assert_eq!(rd.fill_buf().unwrap(), b"");
+
+        // recover the compressed source
+        let source = rd.into_inner().into_inner().into_inner();
+        assert!(source.is_empty());
+    }
+}
@@ -75,3 +75,105 @@ fn test_macro_byteordered() {
     });
     assert_eq!(v, 258);
 }
+
+#[test]
+fn test_macro_owned() {
+    let (v, sink) = with_order!(owned Vec::new(), Endianness::Big, |data| {
+        data.write_u16(0x0102).unwrap();
+        data.write_u16(0x0304).unwrap();
+        7
+    });
+    assert_eq!(v, 7);
+    assert_eq!(sink, vec![1, 2, 3, 4]);
+
+    let (v, rest) = with_order!(owned & sink[..], Endianness::Little, |data| {
+        data.read_u16().unwrap()
+    });
+    assert_eq!(v, 0x0201);
+    assert_eq!(rest, &[3, 4]);
+}
+
+#[test]
+fn test_macro_static_order() {
+    use byteordered::byteorder::{BigEndian, LittleEndian};
+    use byteordered::StaticEndianness;
+
+    let x: &[u8] = &[1, 2, 3, 4];
+    let v = with_static_order!(x, StaticEndianness::<LittleEndian>::new(), |data| {
+        data.read_u16().unwrap()
+    });
+    assert_eq!(v, 0x0201);
+    let v = with_static_order!(x, StaticEndianness::<BigEndian>::new(), |data| {
+        data.read_u16().unwrap()
+    });
+    assert_eq!(v, 0x0102);
+
+    let mut c = x;
+    let mut sink = Vec::new();
+    with_static_order!(
+        (&mut c, &mut sink),
+        StaticEndianness::<BigEndian>::new(),
+        |input, output| {
+            let v = input.read_u32().unwrap();
+            output.write_u32(v + 1).unwrap();
+        }
+    );
+    assert!(c.is_empty());
+    assert_eq!(sink, vec![1, 2, 3, 5]);
+}
+
+#[test]
+fn test_assert_reads() {
+    let data = [0x12, 0x34, 0x56, 0x78, 0x21, 0x43, 0x65, 0x87];
+    assert_reads!(&data, Endianness::Little, u32 = 0x7856_3412, u16 = 0x4321);
+    assert_reads!(
+        &data[..],
+        Endianness::Big,
+        u8 = 0x12,
+        i8 = 0x34,
+        u16 = 0x5678,
+        i32 = 0x2143_6587,
+    );
+    assert_reads!(data.to_vec(), Endianness::Big, u64 = 0x1234_5678_2143_6587);
+    assert_reads!(
+        &[0x40, 0x49, 0x0F, 0xDB][..],
+        byteordered::StaticEndianness::<byteordered::byteorder::BigEndian>::default(),
+        f32 = ::std::f32::consts::PI
+    );
+}
+
+#[test]
+#[should_panic(expected = "unexpected value of field #1 (u16)")]
+fn test_assert_reads_mismatch() {
+    let data = [0x12, 0x34, 0x56, 0x78];
+    assert_reads!(&data, Endianness::Big, u16 = 0x1234, u16 = 0x5679);
+}
+
+#[test]
+#[should_panic(expected = "failed to read field #2 (u32)")]
+fn test_assert_reads_eof() {
+    let data = [0x12, 0x34, 0x56, 0x78];
+    assert_reads!(&data, Endianness::Big, u8 = 0x12, u8 = 0x34, u32 = 0);
+}
+
+/// The macro must not depend on the caller's imports.
+mod hygiene {
+    /// A local type which shadows the crate's `Endianness` by name.
+    #[allow(dead_code)]
+    enum Endianness {
+        Big,
+        Little,
+    }
+
+    #[test]
+    fn test_macro_without_endianness_import() {
+        let e = ::byteordered::Endianness::Big;
+        let x: &[u8] = &[1, 2, 3, 4];
+        let value = with_order!(x, e, |rd| rd.read_u32().unwrap());
+        assert_eq!(value, 0x0102_0304);
+
+        let rd = ::byteordered::ByteOrdered::runtime(x, ::byteordered::Endianness::Little);
+        let value = with_order!(rd, |rd| rd.read_u16().unwrap());
+        assert_eq!(value, 0x0201);
+    }
+}